@@ -0,0 +1,68 @@
+use std::hint::black_box;
+
+use aoc_2024::{days::*, read_input, solution::Solution};
+use criterion::{
+    criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, Criterion,
+};
+
+/// Benchmarks parsing and both parts of `S` on its examples and, if present,
+/// on the real input.
+fn bench_day<S: Solution>(c: &mut Criterion, day: u32) {
+    let mut group = c.benchmark_group(format!("day{day}"));
+
+    if S::EXAMPLE == S::EXAMPLE_PART2 {
+        bench_input::<S>(&mut group, "example", S::EXAMPLE, true, true);
+    } else {
+        bench_input::<S>(&mut group, "example1", S::EXAMPLE, true, false);
+        bench_input::<S>(&mut group, "example2", S::EXAMPLE_PART2, false, true);
+    }
+
+    match read_input(&format!("day{day}")) {
+        Ok(input) => bench_input::<S>(&mut group, "input", &input, true, true),
+        Err(e) => eprintln!("skipping real input of day {day}: {e}"),
+    }
+
+    group.finish();
+}
+
+fn bench_input<S: Solution>(
+    group: &mut BenchmarkGroup<WallTime>,
+    name: &str,
+    input: &str,
+    part1: bool,
+    part2: bool,
+) {
+    group.bench_function(format!("{name}/parse"), |b| {
+        b.iter(|| S::parse(black_box(input)).unwrap())
+    });
+
+    let parsed = S::parse(input).unwrap();
+    if part1 {
+        group.bench_function(format!("{name}/part1"), |b| {
+            b.iter(|| S::part1(black_box(&parsed)))
+        });
+    }
+    if part2 {
+        group.bench_function(format!("{name}/part2"), |b| {
+            b.iter(|| S::part2(black_box(&parsed)))
+        });
+    }
+}
+
+fn days(c: &mut Criterion) {
+    bench_day::<Day1>(c, 1);
+    bench_day::<Day2>(c, 2);
+    bench_day::<Day3>(c, 3);
+    bench_day::<Day4>(c, 4);
+    bench_day::<Day5>(c, 5);
+    bench_day::<Day6>(c, 6);
+    bench_day::<Day7>(c, 7);
+    bench_day::<Day8>(c, 8);
+    bench_day::<Day9>(c, 9);
+    bench_day::<Day10>(c, 10);
+    bench_day::<Day11>(c, 11);
+    bench_day::<Day12>(c, 12);
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
@@ -0,0 +1,56 @@
+use std::hint::black_box;
+
+use aoc_2024::{
+    containers::Vec2D, coord::Bounds2D, iters::IteratorExtensions, util::concat_numbers,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Size of the square grids, matching the typical size of real inputs.
+const GRID_SIZE: usize = 140;
+
+fn vec2d(c: &mut Criterion) {
+    let text = (0..GRID_SIZE)
+        .map(|l| {
+            (0..GRID_SIZE)
+                .map(|c| if (l * c) % 7 == 0 { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    c.bench_function("Vec2D::from_lines", |b| {
+        b.iter(|| Vec2D::from_lines(black_box(&text).lines().map(|l| l.chars())))
+    });
+}
+
+fn bounds2d(c: &mut Criterion) {
+    let bounds = Bounds2D::new(GRID_SIZE as u32, GRID_SIZE as u32);
+
+    c.bench_function("Bounds2D::positions", |b| {
+        b.iter(|| black_box(&bounds).positions().count())
+    });
+}
+
+fn iters(c: &mut Criterion) {
+    c.bench_function("IteratorExtensions::pairs", |b| {
+        b.iter(|| {
+            (0..black_box(10_000u64))
+                .pairs()
+                .map(|(a, b)| a * b)
+                .sum::<u64>()
+        })
+    });
+}
+
+fn util(c: &mut Criterion) {
+    c.bench_function("util::concat_numbers", |b| {
+        b.iter(|| {
+            (1..black_box(1_000u64))
+                .map(|n| concat_numbers::<10>(n, n * 7))
+                .sum::<u64>()
+        })
+    });
+}
+
+criterion_group!(benches, vec2d, bounds2d, iters, util);
+criterion_main!(benches);
@@ -0,0 +1,230 @@
+use std::{
+    collections::BTreeMap,
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use toml::{Table, Value};
+
+use crate::error::{Error, Result};
+
+/// Environment variable overriding [`DEFAULT_ANSWERS_FILE`].
+pub const ANSWERS_FILE_VAR: &str = "AOC_ANSWERS_FILE";
+pub const DEFAULT_ANSWERS_FILE: &str = "answers.toml";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// The outcome of comparing an answer against the ledger.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// No answer has been recorded yet.
+    Unknown,
+    Correct,
+    /// The answer differs from the recorded one.
+    Regression {
+        expected: String,
+    },
+}
+
+/// Known answers, stored as a TOML file with one table per day:
+///
+/// ```toml
+/// [day1]
+/// part1 = "11"
+/// part2 = "31"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ledger {
+    path: PathBuf,
+    answers: BTreeMap<(u32, Part), String>,
+}
+
+impl Part {
+    pub fn all() -> impl Iterator<Item = Self> {
+        [Self::One, Self::Two].iter().cloned()
+    }
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    fn key(&self) -> String {
+        format!("part{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("invalid part '{s}'")),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl Ledger {
+    /// Loads the ledger at `path`. A missing file is an empty ledger.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let text = match fs::read_to_string(&path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(Error::Io(path, e)),
+        };
+
+        let mut ledger = Self::parse(&text)?;
+        ledger.path = path;
+        Ok(ledger)
+    }
+
+    /// Loads the ledger named by [`ANSWERS_FILE_VAR`], or
+    /// [`DEFAULT_ANSWERS_FILE`].
+    pub fn from_env() -> Result<Self> {
+        Self::load(
+            env::var_os(ANSWERS_FILE_VAR)
+                .map_or_else(|| PathBuf::from(DEFAULT_ANSWERS_FILE), PathBuf::from),
+        )
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u32, part: Part, answer: &str) {
+        self.answers.insert((day, part), answer.to_string());
+    }
+
+    pub fn check(&self, day: u32, part: Part, answer: &str) -> Check {
+        match self.get(day, part) {
+            None => Check::Unknown,
+            Some(expected) if expected == answer => Check::Correct,
+            Some(expected) => Check::Regression {
+                expected: expected.to_string(),
+            },
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        fs::write(&self.path, self.to_string()).map_err(|e| Error::Io(self.path.clone(), e))
+    }
+
+    fn parse(text: &str) -> Result<Self> {
+        let table: Table = text.parse().map_err(|e: toml::de::Error| {
+            let start = e.span().map_or(0, |s| s.start);
+            Error::parse_at(text, &text[start..], e.message())
+        })?;
+
+        let mut answers = BTreeMap::new();
+        for (key, value) in &table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| Error::invalid_input(format!("invalid day '{key}'")))?;
+            let Value::Table(parts) = value else {
+                return Err(Error::invalid_input(format!("'{key}' is not a table")));
+            };
+
+            for part in Part::all() {
+                match parts.get(&part.key()) {
+                    None => {}
+                    Some(Value::String(s)) => {
+                        answers.insert((day, part), s.clone());
+                    }
+                    Some(Value::Integer(n)) => {
+                        answers.insert((day, part), n.to_string());
+                    }
+                    Some(_) => {
+                        return Err(Error::invalid_input(format!(
+                            "answer for {key} part {part} is not a string or integer"
+                        )))
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            path: PathBuf::new(),
+            answers,
+        })
+    }
+}
+
+impl fmt::Display for Ledger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut current_day = None;
+        for ((day, part), answer) in &self.answers {
+            if current_day != Some(day) {
+                if current_day.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[day{day}]")?;
+                current_day = Some(day);
+            }
+            writeln!(f, "{} = {}", part.key(), Value::String(answer.clone()))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{Check, Ledger, Part};
+
+    #[test]
+    fn test_parse() {
+        let ledger =
+            Ledger::parse("[day1]\npart1 = \"11\"\npart2 = 31\n\n[day12]\npart2 = \"x\"\n")
+                .unwrap();
+        assert_eq!(ledger.get(1, Part::One), Some("11"));
+        assert_eq!(ledger.get(1, Part::Two), Some("31"));
+        assert_eq!(ledger.get(12, Part::One), None);
+        assert_eq!(ledger.get(12, Part::Two), Some("x"));
+        assert!(Ledger::parse("[dayx]\n").is_err());
+        assert!(Ledger::parse("[day1]\npart1 = [1]\n").is_err());
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut ledger = Ledger::parse("").unwrap();
+        ledger.record(10, Part::One, "36");
+        ledger.record(2, Part::Two, "4");
+        let text = ledger.to_string();
+        assert_eq!(text, "[day2]\npart2 = \"4\"\n\n[day10]\npart1 = \"36\"\n");
+        assert_eq!(Ledger::parse(&text).unwrap(), ledger);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut ledger = Ledger::parse("").unwrap();
+        assert_eq!(ledger.check(1, Part::One, "11"), Check::Unknown);
+        ledger.record(1, Part::One, "11");
+        assert_eq!(ledger.check(1, Part::One, "11"), Check::Correct);
+        assert_eq!(
+            ledger.check(1, Part::One, "12"),
+            Check::Regression {
+                expected: "11".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,566 @@
+use std::{
+    env,
+    io::{stdout, IsTerminal},
+    path::PathBuf,
+    process::ExitCode,
+    time::Duration,
+};
+
+use aoc_2024::{
+    answers::{Check, Ledger, Part},
+    days,
+    error::Result,
+    fetch::{Fetched, Fetcher, Verdict},
+    input::{read_file, read_stdin},
+    read_input,
+    render::Color,
+    solution::{Answers, Runner},
+    timing::{Stats, Timings},
+};
+
+const USAGE: &str = concat!(
+    "usage: aoc [--example | --input <file|->] [--record] [--bench <runs>] [--json] <days>...\n",
+    "       aoc fetch <days>...\n",
+    "       aoc submit <day> <part>\n",
+    "\n",
+    "<days> is a day, a range of days (first..=last or first..last) or all",
+);
+
+enum Command {
+    /// Solve the selected days.
+    Run(Options),
+    /// Download the inputs of the selected days into the input cache.
+    Fetch(Vec<u32>),
+    /// Submit the answer to one part and record it if it is correct.
+    Submit(u32, Part),
+}
+
+/// Where the puzzle input of the selected days is taken from.
+enum Input {
+    /// `dayN` in the input directory.
+    Default,
+    /// The given file, only valid for a single day.
+    File(PathBuf),
+    /// Standard input, only valid for a single day.
+    Stdin,
+    /// The examples from the puzzle descriptions.
+    Example,
+}
+
+struct Options {
+    input: Input,
+    /// Record answers that are not yet in the ledger.
+    record: bool,
+    /// How often each day is run.
+    runs: usize,
+    /// Print the results as JSON instead of a table.
+    json: bool,
+    days: Vec<u32>,
+}
+
+/// The results of running one day.
+struct Row {
+    day: u32,
+    answers: Answers,
+    checks: [Check; 2],
+    /// Timings of parsing, part 1 and part 2.
+    stats: [Stats; 3],
+    /// Timings of parsing the separate part 2 example, for days that have one.
+    parse_part2: Option<Stats>,
+}
+
+#[derive(Clone, Copy)]
+enum Align {
+    Left,
+    Right,
+}
+
+/// A table cell, shown in red if `highlight` is set and stdout is a terminal.
+struct Cell {
+    text: String,
+    highlight: bool,
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match command {
+        Command::Run(options) => run_days(options),
+        Command::Fetch(days) => fetch_days(&days),
+        Command::Submit(day, part) => submit(day, part).unwrap_or_else(|e| {
+            eprintln!("error: day {day}: {e}");
+            ExitCode::FAILURE
+        }),
+    }
+}
+
+fn run_days(options: Options) -> ExitCode {
+    // answers are only known for the real inputs
+    let mut ledger = match options.input {
+        Input::Default => match Ledger::from_env() {
+            Ok(ledger) => Some(ledger),
+            Err(e) => {
+                eprintln!("error: answers: {e}");
+                return ExitCode::FAILURE;
+            }
+        },
+        _ => None,
+    };
+
+    // a failing day is reported and skipped so that the others are still
+    // shown and recorded
+    let mut failed = 0;
+    let mut rows = Vec::new();
+    for day in options.days {
+        let Some(solution) = days::get(day) else {
+            eprintln!("no solution for day {day}");
+            failed += 1;
+            continue;
+        };
+        match run(day, solution, &options.input, options.runs) {
+            Ok((answers, timings)) => {
+                let checks = [Part::One, Part::Two].map(|part| {
+                    ledger
+                        .as_ref()
+                        .map_or(Check::Unknown, |l| l.check(day, part, answers.part(part)))
+                });
+                let stats = |stage: fn(&Timings) -> Duration| {
+                    Stats::from_samples(&timings.iter().map(stage).collect::<Vec<_>>()).unwrap()
+                };
+                let stats = [stats(|t| t.parse), stats(|t| t.part1), stats(|t| t.part2)];
+                let parse_part2 = Stats::from_samples(
+                    &timings
+                        .iter()
+                        .filter_map(|t| t.parse_part2)
+                        .collect::<Vec<_>>(),
+                );
+                rows.push(Row {
+                    day,
+                    answers,
+                    checks,
+                    stats,
+                    parse_part2,
+                });
+            }
+            Err(e) => {
+                eprintln!("error: day {day}: {e}");
+                failed += 1;
+            }
+        }
+    }
+
+    if options.json {
+        print_json(&rows);
+    } else {
+        print_answers(&rows);
+        if options.runs > 1 {
+            println!();
+            print_stats(&rows);
+        }
+    }
+
+    if let (true, Some(ledger)) = (options.record, &mut ledger) {
+        for row in &rows {
+            for (part, check) in Part::all().zip(&row.checks) {
+                if *check == Check::Unknown {
+                    ledger.record(row.day, part, row.answers.part(part));
+                }
+            }
+        }
+        if let Err(e) = ledger.save() {
+            eprintln!("error: answers: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let regressions = rows
+        .iter()
+        .flat_map(|r| &r.checks)
+        .filter(|c| matches!(c, Check::Regression { .. }))
+        .count();
+    if regressions > 0 {
+        eprintln!("{regressions} answer(s) differ from the recorded ones");
+    }
+    if failed > 0 {
+        eprintln!("{failed} day(s) failed");
+    }
+    if regressions > 0 || failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn fetch_days(days: &[u32]) -> ExitCode {
+    let fetcher = Fetcher::from_env();
+
+    for &day in days {
+        match fetcher.fetch(day) {
+            Ok(Fetched::Cached(path)) => {
+                println!("day {day}: already cached at {}", path.display())
+            }
+            Ok(Fetched::Downloaded(path)) => println!("day {day}: saved to {}", path.display()),
+            Err(e) => {
+                eprintln!("error: day {day}: {e}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn submit(day: u32, part: Part) -> Result<ExitCode> {
+    let Some(solution) = days::get(day) else {
+        eprintln!("no solution for day {day}");
+        return Ok(ExitCode::FAILURE);
+    };
+    let (answers, _) = run(day, solution, &Input::Default, 1)?;
+    let answer = answers.part(part);
+
+    let mut ledger = Ledger::from_env()?;
+    match ledger.check(day, part, answer) {
+        Check::Unknown => {}
+        Check::Correct => {
+            println!("day {day} part {part}: {answer} is already known to be correct");
+            return Ok(ExitCode::SUCCESS);
+        }
+        Check::Regression { expected } => {
+            eprintln!("day {day} part {part}: {answer} differs from the recorded {expected}, not submitting");
+            return Ok(ExitCode::FAILURE);
+        }
+    }
+
+    let verdict = Fetcher::from_env().submit(day, part, answer)?;
+    match verdict {
+        Verdict::Correct => {
+            ledger.record(day, part, answer);
+            ledger.save()?;
+            println!(
+                "day {day} part {part}: {answer} is correct, recorded in {}",
+                ledger.path().display()
+            );
+            return Ok(ExitCode::SUCCESS);
+        }
+        Verdict::Wrong { hint: Some(hint) } => {
+            println!("day {day} part {part}: {answer} is wrong, it is {hint}")
+        }
+        Verdict::Wrong { hint: None } => println!("day {day} part {part}: {answer} is wrong"),
+        Verdict::TooSoon { wait: Some(wait) } => {
+            println!("day {day} part {part}: submitted too recently, wait {wait}")
+        }
+        Verdict::TooSoon { wait: None } => {
+            println!("day {day} part {part}: submitted too recently")
+        }
+        Verdict::WrongLevel => {
+            println!("day {day} part {part}: already solved or not unlocked yet")
+        }
+        Verdict::Unrecognized(text) => {
+            println!("day {day} part {part}: unexpected response:\n{text}")
+        }
+    }
+
+    Ok(ExitCode::FAILURE)
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> std::result::Result<Command, String> {
+    let mut args = args.peekable();
+    if args.next_if_eq("fetch").is_some() {
+        let days = parse_day_args(args)?;
+        return Ok(Command::Fetch(days));
+    }
+    if args.next_if_eq("submit").is_some() {
+        let (Some(day), Some(part), None) = (args.next(), args.next(), args.next()) else {
+            return Err("submit requires a day and a part".to_string());
+        };
+        let day = day.parse().map_err(|_| format!("invalid day '{day}'"))?;
+        return Ok(Command::Submit(day, part.parse()?));
+    }
+
+    let mut input = Input::Default;
+    let mut record = false;
+    let mut runs = 1;
+    let mut json = false;
+    let mut selection = Vec::new();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--example" => input = Input::Example,
+            "--input" => {
+                input = match args.next().as_deref() {
+                    Some("-") => Input::Stdin,
+                    Some(path) => Input::File(PathBuf::from(path)),
+                    None => return Err("--input requires a file name".to_string()),
+                }
+            }
+            "--record" => record = true,
+            "--bench" => {
+                runs = args
+                    .next()
+                    .and_then(|n| n.parse().ok())
+                    .filter(|&n| n > 0)
+                    .ok_or("--bench requires a positive number of runs")?
+            }
+            "--json" => json = true,
+            _ => selection.push(arg),
+        }
+    }
+
+    let days = parse_day_args(selection.into_iter())?;
+    if matches!(input, Input::File(_) | Input::Stdin) && days.len() > 1 {
+        return Err("--input can only be used with a single day".to_string());
+    }
+    if record && !matches!(input, Input::Default) {
+        return Err("--record can only be used with the default inputs".to_string());
+    }
+
+    Ok(Command::Run(Options {
+        input,
+        record,
+        runs,
+        json,
+        days,
+    }))
+}
+
+fn parse_day_args(args: impl Iterator<Item = String>) -> std::result::Result<Vec<u32>, String> {
+    let mut days = Vec::new();
+    for arg in args {
+        days.extend(parse_days(&arg).ok_or(format!("invalid day selection '{arg}'"))?);
+    }
+
+    if days.is_empty() {
+        Err("no days selected".to_string())
+    } else {
+        Ok(days)
+    }
+}
+
+/// Runs `day` `runs` times, returning the answers and the timings of every
+/// run.
+fn run(
+    day: u32,
+    solution: &dyn Runner,
+    input: &Input,
+    runs: usize,
+) -> Result<(Answers, Vec<Timings>)> {
+    let text = match input {
+        Input::Default => Some(read_input(&format!("day{day}"))?),
+        Input::File(path) => Some(read_file(path)?),
+        Input::Stdin => Some(read_stdin()?),
+        Input::Example => None,
+    };
+
+    let mut answers = None;
+    let mut timings = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let (a, t) = match &text {
+            Some(text) => solution.run_timed(text)?,
+            None => solution.run_example_timed()?,
+        };
+        answers = Some(a);
+        timings.push(t);
+    }
+
+    Ok((answers.expect("no runs"), timings))
+}
+
+fn parse_days(arg: &str) -> Option<Vec<u32>> {
+    if arg == "all" {
+        Some(days::all().map(|(d, _)| d).collect())
+    } else if let Some((first, last)) = arg.split_once("..=") {
+        Some((first.parse().ok()?..=last.parse().ok()?).collect())
+    } else if let Some((first, last)) = arg.split_once("..") {
+        Some((first.parse().ok()?..last.parse().ok()?).collect())
+    } else {
+        Some(vec![arg.parse().ok()?])
+    }
+}
+
+fn print_answers(rows: &[Row]) {
+    let answer = |answer: &str, check: &Check| match check {
+        Check::Regression { expected } => Cell {
+            text: format!("{answer} (expected {expected})"),
+            highlight: true,
+        },
+        _ => Cell::new(answer),
+    };
+    let time = |stats: &Stats| Cell::new(format!("{:.2?}", stats.median));
+
+    print_table(
+        &[
+            ("Day", Align::Right),
+            ("Part 1", Align::Left),
+            ("Part 2", Align::Left),
+            ("Parse", Align::Right),
+            ("Time 1", Align::Right),
+            ("Time 2", Align::Right),
+        ],
+        rows.iter()
+            .map(|r| {
+                let mut cells = vec![
+                    Cell::new(r.day),
+                    answer(&r.answers.part1, &r.checks[0]),
+                    answer(&r.answers.part2, &r.checks[1]),
+                ];
+                cells.push(match &r.parse_part2 {
+                    Some(p2) => Cell::new(format!("{:.2?} / {:.2?}", r.stats[0].median, p2.median)),
+                    None => time(&r.stats[0]),
+                });
+                cells.extend(r.stats[1..].iter().map(time));
+                cells
+            })
+            .collect(),
+    );
+}
+
+fn print_stats(rows: &[Row]) {
+    let mut table = vec![];
+    for row in rows {
+        let mut stages = vec![("parse", &row.stats[0])];
+        stages.extend(row.parse_part2.as_ref().map(|s| ("parse 2", s)));
+        stages.extend([("part 1", &row.stats[1]), ("part 2", &row.stats[2])]);
+        for (stage, stats) in stages {
+            table.push(vec![
+                Cell::new(row.day),
+                Cell::new(stage),
+                Cell::new(format!("{:.2?}", stats.min)),
+                Cell::new(format!("{:.2?}", stats.median)),
+                Cell::new(format!("{:.2?}", stats.mean)),
+                Cell::new(format!("{:.2?}", stats.stddev)),
+            ]);
+        }
+    }
+
+    print_table(
+        &[
+            ("Day", Align::Right),
+            ("Stage", Align::Left),
+            ("Min", Align::Right),
+            ("Median", Align::Right),
+            ("Mean", Align::Right),
+            ("Stddev", Align::Right),
+        ],
+        table,
+    );
+}
+
+fn print_table(header: &[(&str, Align)], rows: Vec<Vec<Cell>>) {
+    let color = stdout().is_terminal();
+    let widths: Vec<_> = (0..header.len())
+        .map(|c| {
+            rows.iter()
+                .map(|r| r[c].text.chars().count())
+                .chain([header[c].0.len()])
+                .max()
+                .unwrap()
+        })
+        .collect();
+
+    let pad = |text: &str, c: usize| {
+        let w = widths[c];
+        match header[c].1 {
+            Align::Left => format!("{text:<w$}"),
+            Align::Right => format!("{text:>w$}"),
+        }
+    };
+    let line = |cells: Vec<String>| println!("{}", cells.join(" | ").trim_end());
+
+    line(
+        header
+            .iter()
+            .enumerate()
+            .map(|(c, (h, _))| pad(h, c))
+            .collect(),
+    );
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|w| "-".repeat(*w))
+            .collect::<Vec<_>>()
+            .join("-+-")
+    );
+    for row in rows {
+        line(
+            row.into_iter()
+                .enumerate()
+                .map(|(c, cell)| {
+                    let text = pad(&cell.text, c);
+                    if cell.highlight && color {
+                        Color::Red.paint(text)
+                    } else {
+                        text
+                    }
+                })
+                .collect(),
+        );
+    }
+}
+
+fn print_json(rows: &[Row]) {
+    let stats = |s: &Stats| {
+        format!(
+            r#"{{"runs": {}, "min_ns": {}, "median_ns": {}, "mean_ns": {}, "stddev_ns": {}}}"#,
+            s.runs,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.mean.as_nanos(),
+            s.stddev.as_nanos()
+        )
+    };
+
+    let days: Vec<_> = rows
+        .iter()
+        .map(|r| {
+            format!(
+                concat!(
+                    r#"  {{"day": {}, "answers": {{"part1": {}, "part2": {}}}, "#,
+                    r#""timings": {{"parse": {}, {}"part1": {}, "part2": {}}}}}"#
+                ),
+                r.day,
+                json_string(&r.answers.part1),
+                json_string(&r.answers.part2),
+                stats(&r.stats[0]),
+                r.parse_part2.as_ref().map_or(String::new(), |s| format!(
+                    r#""parse_part2": {}, "#,
+                    stats(s)
+                )),
+                stats(&r.stats[1]),
+                stats(&r.stats[2]),
+            )
+        })
+        .collect();
+
+    println!("[\n{}\n]", days.join(",\n"));
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl Cell {
+    fn new(text: impl ToString) -> Self {
+        Self {
+            text: text.to_string(),
+            highlight: false,
+        }
+    }
+}
@@ -1,18 +1,143 @@
+use std::{
+    fmt, mem,
+    num::TryFromIntError,
+    ops::{Add, Index, IndexMut},
+    str::FromStr,
+};
+
 use itertools::Itertools;
 
+use crate::{
+    coord::{BoundedCoord2D, Bounds2D, Coord2D, CoordDiff2D, Direction2D, Direction8, ToOffset2D},
+    error::{self, Error},
+    render::Overlay,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Vec2D<T> {
     cols: usize,
     data: Vec<T>,
 }
 
+/// Why the lines given to [`Vec2D::try_from_lines`] do not form a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vec2DError {
+    /// There are no lines, or the lines are empty.
+    Empty,
+    /// Line `line` (counting from 1) has a different length than the lines
+    /// before it.
+    Ragged {
+        line: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
+
+/// A position in a [`Vec2D`]. Any type converting into it, like [`Coord2D`]
+/// with `x` as the column and `y` as the line, can be used to index a grid.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Vec2DIndex {
     line: usize,
     column: usize,
 }
 
+impl<T: Clone> Vec2D<T> {
+    /// Creates a grid of `lines` lines and `cols` columns filled with `fill`.
+    ///
+    /// Panics if `lines` or `cols` is zero, like [`Vec2D::try_from_lines`]
+    /// rejects empty grids.
+    pub fn new(lines: usize, cols: usize, fill: T) -> Self {
+        assert!(lines > 0 && cols > 0, "empty grid");
+        Self {
+            cols,
+            data: vec![fill; lines * cols],
+        }
+    }
+
+    /// Swaps lines and columns.
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.cols(), self.lines(), |i| {
+            self[Vec2DIndex::new(i.column, i.line)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_cw(&self) -> Self {
+        Self::from_fn(self.cols(), self.lines(), |i| {
+            self[Vec2DIndex::new(self.lines() - 1 - i.column, i.line)].clone()
+        })
+    }
+
+    /// Rotates the grid a quarter turn counterclockwise.
+    pub fn rotate_ccw(&self) -> Self {
+        Self::from_fn(self.cols(), self.lines(), |i| {
+            self[Vec2DIndex::new(i.column, self.cols() - 1 - i.line)].clone()
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Self {
+        Self::from_fn(self.lines(), self.cols(), |i| {
+            self[Vec2DIndex::new(i.line, self.cols() - 1 - i.column)].clone()
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Self {
+        Self::from_fn(self.lines(), self.cols(), |i| {
+            self[Vec2DIndex::new(self.lines() - 1 - i.line, i.column)].clone()
+        })
+    }
+
+    /// Copies the `lines` by `cols` window starting at `top_left`. Returns
+    /// `None` if the window is empty or not entirely in bounds.
+    pub fn subgrid(&self, top_left: &Vec2DIndex, lines: usize, cols: usize) -> Option<Self> {
+        if lines == 0 || cols == 0 {
+            return None;
+        }
+        let bottom_right = top_left.checked_add_signed(
+            isize::try_from(lines).ok()? - 1,
+            isize::try_from(cols).ok()? - 1,
+        )?;
+        if !self.contains(&bottom_right) {
+            return None;
+        }
+
+        Some(Self::from_fn(lines, cols, |i| {
+            self[Vec2DIndex::new(top_left.line + i.line, top_left.column + i.column)].clone()
+        }))
+    }
+}
+
 impl<T> Vec2D<T> {
+    /// Creates a grid of `lines` lines and `cols` columns, calling `f` with
+    /// the index of each element in order.
+    ///
+    /// Panics if `lines` or `cols` is zero.
+    pub fn from_fn(lines: usize, cols: usize, f: impl FnMut(Vec2DIndex) -> T) -> Self {
+        assert!(lines > 0 && cols > 0, "empty grid");
+        Self {
+            cols,
+            data: (0..lines)
+                .cartesian_product(0..cols)
+                .map(|(l, c)| Vec2DIndex::new(l, c))
+                .map(f)
+                .collect(),
+        }
+    }
+
+    /// Panics if the lines do not form a grid, see [`Vec2D::try_from_lines`].
     pub fn from_lines<I>(lines: I) -> Self
+    where
+        I: Iterator,
+        I::Item: IntoIterator<Item = T>,
+    {
+        Self::try_from_lines(lines).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    /// Builds a grid from its lines, which must all have the same, non-zero
+    /// length.
+    pub fn try_from_lines<I>(lines: I) -> Result<Self, Vec2DError>
     where
         I: Iterator,
         I::Item: IntoIterator<Item = T>,
@@ -20,34 +145,165 @@ impl<T> Vec2D<T> {
         let mut cols = None;
         let mut data = vec![];
 
-        for line in lines {
-            let line = line.into_iter().collect::<Vec<_>>();
-            if cols.is_some() && cols.unwrap() != line.len() {
-                panic!("lines with different lengths");
-            } else {
-                cols = Some(line.len());
-            }
+        for (i, line) in lines.enumerate() {
+            let start = data.len();
             data.extend(line);
+            let actual = data.len() - start;
+            match cols {
+                None => cols = Some(actual),
+                Some(expected) if expected != actual => {
+                    return Err(Vec2DError::Ragged {
+                        line: i + 1,
+                        expected,
+                        actual,
+                    })
+                }
+                Some(_) => {}
+            }
         }
 
-        Self {
-            cols: cols.expect("no input lines"),
-            data,
+        match cols {
+            Some(cols) if cols > 0 => Ok(Self { cols, data }),
+            _ => Err(Vec2DError::Empty),
         }
     }
 
-    pub fn get(&self, line: usize, column: usize) -> Option<&T> {
-        if line >= self.lines() || column >= self.cols() {
-            None
-        } else {
-            self.data.get(self.index(line, column))
+    /// Starts rendering the grid with some positions highlighted.
+    pub fn overlay(&self) -> Overlay<'_, T> {
+        Overlay::new(self)
+    }
+
+    /// Converts every element with `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Vec2D<U> {
+        Vec2D {
+            cols: self.cols,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// Converts every element with `f`, which also gets its index.
+    pub fn map_indexed<U>(&self, mut f: impl FnMut(Vec2DIndex, &T) -> U) -> Vec2D<U> {
+        Vec2D {
+            cols: self.cols,
+            data: self.enumerated_iter().map(|(i, e)| f(i, e)).collect(),
         }
     }
 
-    pub fn get_index(&self, index: &Vec2DIndex) -> Option<&T> {
+    pub fn get(&self, line: usize, column: usize) -> Option<&T> {
+        self.data.get(self.checked_index(line, column)?)
+    }
+
+    pub fn get_index<I: Into<Vec2DIndex> + Copy>(&self, index: &I) -> Option<&T> {
+        let index = (*index).into();
         self.get(index.line, index.column)
     }
 
+    pub fn get_mut(&mut self, line: usize, column: usize) -> Option<&mut T> {
+        let i = self.checked_index(line, column)?;
+        Some(&mut self.data[i])
+    }
+
+    pub fn get_index_mut<I: Into<Vec2DIndex> + Copy>(&mut self, index: &I) -> Option<&mut T> {
+        let index = (*index).into();
+        self.get_mut(index.line, index.column)
+    }
+
+    /// Replaces the element at `index`, returning the previous one.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set<I: Into<Vec2DIndex> + Copy>(&mut self, index: &I, value: T) -> T {
+        mem::replace(&mut self[*index], value)
+    }
+
+    /// Replaces the element at `index`, returning the previous one, or `None`
+    /// if `index` is out of bounds.
+    pub fn try_set<I: Into<Vec2DIndex> + Copy>(&mut self, index: &I, value: T) -> Option<T> {
+        Some(mem::replace(self.get_index_mut(index)?, value))
+    }
+
+    /// Swaps the elements at `a` and `b`.
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn swap(&mut self, a: &Vec2DIndex, b: &Vec2DIndex) {
+        if !self.try_swap(a, b) {
+            panic!(
+                "{}",
+                self.out_of_bounds(if self.contains(a) { b } else { a })
+            );
+        }
+    }
+
+    /// Swaps the elements at `a` and `b`, returns `false` without swapping
+    /// if either index is out of bounds.
+    pub fn try_swap(&mut self, a: &Vec2DIndex, b: &Vec2DIndex) -> bool {
+        match (
+            self.checked_index(a.line, a.column),
+            self.checked_index(b.line, b.column),
+        ) {
+            (Some(a), Some(b)) => {
+                self.data.swap(a, b);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Iterates over the horizontally and vertically adjacent elements that
+    /// are in bounds, tagged with the direction they are in.
+    pub fn neighbors4(
+        &self,
+        index: &Vec2DIndex,
+    ) -> impl Iterator<Item = (Direction2D, Vec2DIndex, &T)> {
+        self.neighbors_with(index, Direction2D::all())
+    }
+
+    /// Iterates over the horizontally, vertically and diagonally adjacent
+    /// elements that are in bounds, tagged with the direction they are in.
+    pub fn neighbors8(
+        &self,
+        index: &Vec2DIndex,
+    ) -> impl Iterator<Item = (Direction8, Vec2DIndex, &T)> {
+        self.neighbors_with(index, Direction8::all())
+    }
+
+    /// Iterates over the elements at each offset in `stencil` that are in
+    /// bounds, tagged with the offset they were reached by.
+    pub fn neighbors_with<'a, D: ToOffset2D + 'a>(
+        &'a self,
+        index: &Vec2DIndex,
+        stencil: impl IntoIterator<Item = D> + 'a,
+    ) -> impl Iterator<Item = (D, Vec2DIndex, &'a T)> {
+        let index = *index;
+        stencil.into_iter().filter_map(move |tag| {
+            let neighbor = (index + tag.to_offset())?;
+            Some((tag, neighbor, self.get_index(&neighbor)?))
+        })
+    }
+
+    pub fn contains<I: Into<Vec2DIndex> + Copy>(&self, index: &I) -> bool {
+        let index = (*index).into();
+        self.checked_index(index.line, index.column).is_some()
+    }
+
+    /// The size of the grid, for use with [`Coord2D`].
+    ///
+    /// Panics if the grid is too large to be described by `u32`s.
+    pub fn bounds(&self) -> Bounds2D {
+        Bounds2D::new(
+            u32::try_from(self.cols()).expect("grid too wide"),
+            u32::try_from(self.lines()).expect("grid too high"),
+        )
+    }
+
+    /// Converts `index` into a coordinate bounded by [`Vec2D::bounds`], or
+    /// returns `None` if it is out of bounds.
+    pub fn bounded(&self, index: &Vec2DIndex) -> Option<BoundedCoord2D> {
+        if !self.contains(index) {
+            return None;
+        }
+        Some(Coord2D::try_from(*index).ok()?.into_bounded(self.bounds()))
+    }
+
     pub fn cols(&self) -> usize {
         self.cols
     }
@@ -66,9 +322,148 @@ impl<T> Vec2D<T> {
             .map(|(l, c)| Vec2DIndex::new(l, c))
     }
 
+    /// Iterates over line `line` from left to right.
+    ///
+    /// Panics if `line` is out of bounds.
+    pub fn row(&self, line: usize) -> impl DoubleEndedIterator<Item = (Vec2DIndex, &T)> {
+        self.assert_in_bounds(&Vec2DIndex::new(line, 0));
+        self.ray(Vec2DIndex::new(line, 0), (0, 1), self.cols())
+    }
+
+    /// Iterates over column `column` from top to bottom.
+    ///
+    /// Panics if `column` is out of bounds.
+    pub fn column(&self, column: usize) -> impl DoubleEndedIterator<Item = (Vec2DIndex, &T)> {
+        self.assert_in_bounds(&Vec2DIndex::new(0, column));
+        self.ray(Vec2DIndex::new(0, column), (1, 0), self.lines())
+    }
+
+    /// Iterates over all lines, see [`Vec2D::row`].
+    pub fn rows(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = (Vec2DIndex, &T)>> + '_ {
+        (0..self.lines()).map(|l| self.row(l))
+    }
+
+    /// Iterates over all columns, see [`Vec2D::column`].
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = (Vec2DIndex, &T)>> + '_ {
+        (0..self.cols()).map(|c| self.column(c))
+    }
+
+    /// Iterates over all diagonals running from the top left to the bottom
+    /// right, starting with the one in the bottom left corner.
+    pub fn diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = (Vec2DIndex, &T)>> + '_ {
+        let starts = (0..self.lines())
+            .rev()
+            .map(|l| Vec2DIndex::new(l, 0))
+            .chain((1..self.cols()).map(|c| Vec2DIndex::new(0, c)));
+        starts.map(|s| {
+            let len = (self.lines() - s.line).min(self.cols() - s.column);
+            self.ray(s, (1, 1), len)
+        })
+    }
+
+    /// Iterates over all diagonals running from the top right to the bottom
+    /// left, starting with the one in the top left corner.
+    pub fn anti_diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = (Vec2DIndex, &T)>> + '_ {
+        let starts = (0..self.cols())
+            .map(|c| Vec2DIndex::new(0, c))
+            .chain((1..self.lines()).map(|l| Vec2DIndex::new(l, self.cols() - 1)));
+        starts.map(|s| {
+            let len = (self.lines() - s.line).min(s.column + 1);
+            self.ray(s, (1, -1), len)
+        })
+    }
+
+    /// `len` elements starting at `start`, each `step` away from the previous
+    /// one. All of them must be in bounds.
+    fn ray(
+        &self,
+        start: Vec2DIndex,
+        (lines, columns): (isize, isize),
+        len: usize,
+    ) -> impl DoubleEndedIterator<Item = (Vec2DIndex, &T)> {
+        (0..len as isize).map(move |i| {
+            let index = start.checked_add_signed(lines * i, columns * i).unwrap();
+            (index, &self[index])
+        })
+    }
+
     fn index(&self, l: usize, c: usize) -> usize {
         l * self.cols() + c
     }
+
+    fn assert_in_bounds(&self, index: &Vec2DIndex) {
+        if !self.contains(index) {
+            panic!("{}", self.out_of_bounds(index));
+        }
+    }
+
+    fn checked_index(&self, l: usize, c: usize) -> Option<usize> {
+        if l >= self.lines() || c >= self.cols() {
+            None
+        } else {
+            Some(self.index(l, c))
+        }
+    }
+
+    fn out_of_bounds(&self, index: &Vec2DIndex) -> String {
+        format!(
+            "index {index:?} out of bounds for {}x{} grid",
+            self.lines(),
+            self.cols()
+        )
+    }
+}
+
+/// Writes each line followed by a newline, so grids of chars are shown as
+/// the text they were parsed from.
+impl<T: fmt::Display> fmt::Display for Vec2D<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for (_, e) in row {
+                write!(f, "{e}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Vec2D<char> {
+    type Err = Vec2DError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_lines(grid_lines(s).map(str::chars))
+    }
+}
+
+impl<T, I: Into<Vec2DIndex>> Index<I> for Vec2D<T> {
+    type Output = T;
+
+    fn index(&self, index: I) -> &Self::Output {
+        let index = index.into();
+        match self.checked_index(index.line, index.column) {
+            Some(i) => &self.data[i],
+            None => panic!("{}", self.out_of_bounds(&index)),
+        }
+    }
+}
+
+impl<T, I: Into<Vec2DIndex>> IndexMut<I> for Vec2D<T> {
+    fn index_mut(&mut self, index: I) -> &mut Self::Output {
+        let index = index.into();
+        match self.checked_index(index.line, index.column) {
+            Some(i) => &mut self.data[i],
+            None => panic!("{}", self.out_of_bounds(&index)),
+        }
+    }
 }
 
 impl Vec2DIndex {
@@ -76,6 +471,14 @@ impl Vec2DIndex {
         Self { line, column }
     }
 
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
     pub fn checked_add_signed(&self, lines: isize, columns: isize) -> Option<Self> {
         Some(Self::new(
             self.line.checked_add_signed(lines)?,
@@ -83,3 +486,350 @@ impl Vec2DIndex {
         ))
     }
 }
+
+impl From<Coord2D> for Vec2DIndex {
+    fn from(coord: Coord2D) -> Self {
+        Self::new(coord.y() as usize, coord.x() as usize)
+    }
+}
+
+impl From<Vec2DIndex> for Coord2D<usize> {
+    fn from(index: Vec2DIndex) -> Self {
+        Coord2D::new(index.column, index.line)
+    }
+}
+
+impl From<BoundedCoord2D> for Vec2DIndex {
+    fn from(coord: BoundedCoord2D) -> Self {
+        coord.unbounded().into()
+    }
+}
+
+impl TryFrom<Vec2DIndex> for Coord2D {
+    type Error = TryFromIntError;
+
+    fn try_from(index: Vec2DIndex) -> Result<Self, Self::Error> {
+        Ok(Coord2D::new(
+            index.column.try_into()?,
+            index.line.try_into()?,
+        ))
+    }
+}
+
+impl Add<CoordDiff2D> for Vec2DIndex {
+    type Output = Option<Self>;
+
+    /// Moves `dy` lines and `dx` columns.
+    fn add(self, rhs: CoordDiff2D) -> Self::Output {
+        self.checked_add_signed(rhs.dy as isize, rhs.dx as isize)
+    }
+}
+
+impl fmt::Display for Vec2DError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Vec2DError::Empty => write!(f, "empty grid"),
+            Vec2DError::Ragged {
+                line,
+                expected,
+                actual,
+            } => write!(f, "line {line} has {actual} columns, expected {expected}"),
+        }
+    }
+}
+
+impl std::error::Error for Vec2DError {}
+
+/// Parses a grid with one element per character, converting each with `f`.
+/// Trailing newlines are ignored.
+pub fn parse_grid<T, E: fmt::Display>(
+    input: &str,
+    mut f: impl FnMut(char) -> Result<T, E>,
+) -> error::Result<Vec2D<T>> {
+    let lines = grid_lines(input)
+        .map(|l| {
+            l.char_indices()
+                .map(|(i, c)| f(c).map_err(|e| Error::parse_at(input, &l[i..], e)))
+                .collect::<error::Result<Vec<_>>>()
+        })
+        .collect::<error::Result<Vec<_>>>()?;
+
+    Ok(Vec2D::try_from_lines(lines.into_iter())?)
+}
+
+fn grid_lines(input: &str) -> impl Iterator<Item = &str> {
+    input.trim_end_matches(['\r', '\n']).lines()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::prelude::*;
+
+    use crate::{
+        containers::{parse_grid, Vec2D, Vec2DError, Vec2DIndex},
+        coord::{Coord2D, CoordDiff2D, Direction2D, Direction8},
+        error::Error,
+    };
+
+    #[test]
+    fn test_views() {
+        let vec: Vec2D<char> = "abc\ndef\n".parse().unwrap();
+        let text = |line: &mut dyn DoubleEndedIterator<Item = (Vec2DIndex, &char)>| {
+            line.map(|(_, c)| c).collect::<String>()
+        };
+
+        assert_eq!(text(&mut vec.row(1)), "def");
+        assert_eq!(text(&mut vec.row(1).rev()), "fed");
+        assert_eq!(text(&mut vec.column(2)), "cf");
+        assert_eq!(
+            vec.row(1).map(|(i, _)| i).collect::<Vec<_>>(),
+            [
+                Vec2DIndex::new(1, 0),
+                Vec2DIndex::new(1, 1),
+                Vec2DIndex::new(1, 2)
+            ]
+        );
+        assert_eq!(
+            vec.rows().map(|mut r| text(&mut r)).collect::<Vec<_>>(),
+            ["abc", "def"]
+        );
+        assert_eq!(
+            vec.columns().map(|mut c| text(&mut c)).collect::<Vec<_>>(),
+            ["ad", "be", "cf"]
+        );
+        assert_eq!(
+            vec.diagonals()
+                .map(|mut d| text(&mut d))
+                .collect::<Vec<_>>(),
+            ["d", "ae", "bf", "c"]
+        );
+        assert_eq!(
+            vec.anti_diagonals()
+                .map(|mut d| text(&mut d))
+                .collect::<Vec<_>>(),
+            ["a", "bd", "ce", "f"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_row_out_of_bounds() {
+        let vec = Vec2D::new(2, 3, 0);
+        let _ = vec.row(2);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let vec: Vec2D<char> = "abc\ndef\nghi\n".parse().unwrap();
+        let corner = Vec2DIndex::new(0, 0);
+        let center = Vec2DIndex::new(1, 1);
+
+        assert_eq!(
+            vec.neighbors4(&corner).collect::<Vec<_>>(),
+            [
+                (Direction2D::South, Vec2DIndex::new(1, 0), &'d'),
+                (Direction2D::East, Vec2DIndex::new(0, 1), &'b'),
+            ]
+        );
+        assert_eq!(
+            vec.neighbors4(&center)
+                .map(|(_, _, c)| c)
+                .collect::<String>(),
+            "bhfd"
+        );
+        assert_eq!(
+            vec.neighbors8(&center)
+                .map(|(_, _, c)| c)
+                .collect::<String>(),
+            "bcfihgda"
+        );
+        assert_eq!(
+            vec.neighbors8(&corner)
+                .map(|(d, _, _)| d)
+                .collect::<Vec<_>>(),
+            [Direction8::East, Direction8::SouthEast, Direction8::South]
+        );
+
+        let knight = [(2, 1), (1, 2), (2, -1)].map(|(dx, dy)| CoordDiff2D { dx, dy });
+        assert_eq!(
+            vec.neighbors_with(&corner, knight)
+                .map(|(_, i, c)| (i, *c))
+                .collect::<Vec<_>>(),
+            [(Vec2DIndex::new(1, 2), 'f'), (Vec2DIndex::new(2, 1), 'h')]
+        );
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = |text: &str| text.parse::<Vec2D<char>>().unwrap();
+        let vec = grid("abc\ndef\n");
+
+        assert_eq!(vec.transpose(), grid("ad\nbe\ncf\n"));
+        assert_eq!(vec.rotate_cw(), grid("da\neb\nfc\n"));
+        assert_eq!(vec.rotate_ccw(), grid("cf\nbe\nad\n"));
+        assert_eq!(vec.flip_horizontal(), grid("cba\nfed\n"));
+        assert_eq!(vec.flip_vertical(), grid("def\nabc\n"));
+        assert_eq!(vec.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), vec);
+        assert_ne!(vec.rotate_cw().rotate_cw(), vec);
+
+        let top_left = Vec2DIndex::new(0, 1);
+        assert_eq!(vec.subgrid(&top_left, 2, 2), Some(grid("bc\nef\n")));
+        assert!(vec.subgrid(&top_left, 2, 3).is_none());
+        assert!(vec.subgrid(&top_left, 0, 1).is_none());
+
+        assert_eq!(
+            vec.map(|&c| c as u8 - b'a'),
+            Vec2D::from_lines([[0, 1, 2], [3, 4, 5]].into_iter())
+        );
+        assert_eq!(
+            vec.map_indexed(|i, _| i.line * 10 + i.column),
+            Vec2D::from_lines([[0, 1, 2], [10, 11, 12]].into_iter())
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let text = "abc\ndef\n";
+        assert_eq!(text.parse::<Vec2D<char>>().unwrap().to_string(), text);
+        assert_eq!(
+            parse_grid("12\n34", |c| c.to_digit(10).ok_or(""))
+                .unwrap()
+                .map(|d| d * 2)
+                .to_string(),
+            "24\n68\n"
+        );
+    }
+
+    #[test]
+    fn test_coords() {
+        let mut vec: Vec2D<char> = "abc\ndef\n".parse().unwrap();
+        let bounds = vec.bounds();
+        assert_eq!((bounds.width(), bounds.height()), (3, 2));
+
+        let coord = Coord2D::new(2, 1);
+        assert_eq!(Vec2DIndex::from(coord), Vec2DIndex::new(1, 2));
+        assert_eq!(Coord2D::try_from(Vec2DIndex::new(1, 2)), Ok(coord));
+        assert_eq!(
+            Coord2D::<usize>::from(Vec2DIndex::new(1, 2)),
+            Coord2D::new(2, 1)
+        );
+        assert_eq!(vec[coord], 'f');
+        assert_eq!(vec[coord.into_bounded(bounds)], 'f');
+        assert_eq!(vec.get_index(&Coord2D::new(3, 0)), None);
+
+        vec[Coord2D::new(0, 1)] = 'x';
+        assert_eq!(vec.set(&Coord2D::new(1, 0), 'y'), 'b');
+        assert_eq!(vec.to_string(), "ayc\nxef\n");
+
+        assert_eq!(
+            vec.bounded(&Vec2DIndex::new(1, 2)),
+            Some(coord.into_bounded(bounds))
+        );
+        assert_eq!(vec.bounded(&Vec2DIndex::new(2, 0)), None);
+    }
+
+    #[test]
+    fn test_try_from_lines() {
+        assert_eq!(
+            "ab\ncd\n".parse::<Vec2D<char>>(),
+            Ok(Vec2D::from_lines([['a', 'b'], ['c', 'd']].into_iter()))
+        );
+        assert_eq!(
+            "ab\ncd\ne".parse::<Vec2D<char>>().err(),
+            Some(Vec2DError::Ragged {
+                line: 3,
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!("\n".parse::<Vec2D<char>>().err(), Some(Vec2DError::Empty));
+        assert_eq!(
+            Vec2D::<u8>::try_from_lines([[], []].into_iter()).err(),
+            Some(Vec2DError::Empty)
+        );
+    }
+
+    #[test]
+    fn test_parse_grid() {
+        let digit = |c: char| c.to_digit(10).ok_or("not a digit");
+        assert_eq!(
+            parse_grid("12\n34\n\n", digit).unwrap(),
+            Vec2D::from_lines([[1, 2], [3, 4]].into_iter())
+        );
+        match parse_grid("12\n3x\n", digit) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+            _ => panic!("expected a parse error"),
+        }
+        match parse_grid("12\n345\n", digit) {
+            Err(Error::Parse { line, column, .. }) => assert_eq!((line, column), (2, 3)),
+            _ => panic!("expected a parse error"),
+        }
+    }
+
+    #[test]
+    fn test_mutate() {
+        let mut vec = Vec2D::new(2, 3, 0);
+        let before = vec.clone();
+        assert_eq!((vec.lines(), vec.cols()), (2, 3));
+
+        let (a, b) = (Vec2DIndex::new(0, 2), Vec2DIndex::new(1, 0));
+        *vec.get_mut(0, 0).unwrap() = 1;
+        assert_eq!(vec.set(&a, 2), 0);
+        vec[b] = 3;
+        assert_eq!(vec.get(0, 0), Some(&1));
+        assert_eq!((vec[a], vec[b]), (2, 3));
+
+        vec.swap(&a, &b);
+        assert_eq!((vec[a], vec[b]), (3, 2));
+
+        let outside = Vec2DIndex::new(2, 0);
+        assert_eq!(vec.get_index_mut(&outside), None);
+        assert_eq!(vec.try_set(&outside, 4), None);
+        assert!(!vec.try_swap(&a, &outside));
+        assert!(!vec.contains(&outside));
+        assert_eq!(vec, Vec2D::from_lines([[1, 0, 3], [2, 0, 0]].into_iter()));
+        assert_ne!(vec, before);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty grid")]
+    fn test_new_without_lines() {
+        let _ = Vec2D::new(0, 3, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty grid")]
+    fn test_from_fn_without_columns() {
+        let _ = Vec2D::from_fn(2, 0, |_| 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index Vec2DIndex { line: 0, column: 3 } out of bounds for 2x3 grid")]
+    fn test_index_out_of_bounds() {
+        let mut vec = Vec2D::new(2, 3, 0);
+        vec[Vec2DIndex::new(0, 3)] = 1;
+    }
+
+    proptest! {
+        #[test]
+        fn prop_indizes_are_exhaustive(lines in 1..20usize, cols in 1..20usize) {
+            let vec = Vec2D::from_lines((0..lines).map(|l| (0..cols).map(move |c| (l, c))));
+            prop_assert_eq!((vec.lines(), vec.cols()), (lines, cols));
+
+            let indizes = vec.indizes().collect::<Vec<_>>();
+            prop_assert_eq!(indizes.len(), lines * cols);
+            prop_assert_eq!(indizes.iter().collect::<HashSet<_>>().len(), lines * cols);
+            for i in indizes {
+                prop_assert_eq!(vec.get_index(&i), Some(&(i.line, i.column)));
+            }
+        }
+
+        #[test]
+        fn prop_get_out_of_bounds(lines in 1..20usize, cols in 1..20usize, l in 0..40usize, c in 0..40usize) {
+            let vec = Vec2D::from_lines((0..lines).map(|_| (0..cols).map(|_| ())));
+            prop_assert_eq!(vec.get_index(&Vec2DIndex::new(l, c)).is_some(), l < lines && c < cols);
+        }
+    }
+}
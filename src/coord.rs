@@ -1,4 +1,13 @@
-use std::ops::{Add, Mul, Sub};
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    iter::{from_fn, successors},
+    ops::{Add, Div, Index, Mul, Neg, Sub},
+};
+
+use itertools::Itertools;
+
+use crate::util::gcd;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Direction2D {
@@ -8,22 +17,77 @@ pub enum Direction2D {
     East,
 }
 
+/// The four cardinal and four diagonal compass directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Bounds2D {
     width: u32,
     height: u32,
 }
 
+/// A position on a grid. Positions are unsigned by default, but any
+/// [`CoordNum`] can be used for other puzzles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coord2D<T = u32> {
+    x: T,
+    y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CoordDiff2D<T = i32> {
+    pub dx: T,
+    pub dy: T,
+}
+
+/// A position in space. Signed by default, as 3D puzzles rarely come with
+/// a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Coord3D<T = i64> {
+    x: T,
+    y: T,
+    z: T,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Coord2D {
-    x: u32,
-    y: u32,
+pub struct CoordDiff3D<T = i64> {
+    pub dx: T,
+    pub dy: T,
+    pub dz: T,
 }
 
+/// A position with `N` dimensions, for when three are not enough.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct CoordDiff2D {
-    pub dx: i32,
-    pub dy: i32,
+pub struct Point<const N: usize, T = i64> {
+    coords: [T; N],
+}
+
+/// An integer type the coordinates can be made of.
+///
+/// All arithmetic on coordinates goes through `i128`, so mixing widths and
+/// signedness is lossless and overflow is detected when narrowing back.
+pub trait CoordNum: Copy + Ord + Hash + Debug {
+    /// The type differences between two such numbers are stored in.
+    type Signed: CoordNum;
+
+    fn to_i128(self) -> i128;
+
+    fn from_i128(n: i128) -> Option<Self>;
+}
+
+/// Something describing a step on a grid, e.g. a direction.
+pub trait ToOffset2D {
+    fn to_offset(&self) -> CoordDiff2D;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -32,6 +96,82 @@ pub struct BoundedCoord2D {
     bounds: Bounds2D,
 }
 
+/// A coordinate on a torus: leaving the bounds on one side enters them on
+/// the opposite side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WrappingCoord2D {
+    coord: Coord2D,
+    bounds: Bounds2D,
+}
+
+/// The coordinates reached from `origin` by repeatedly taking `step`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Ray2D {
+    origin: Coord2D,
+    step: CoordDiff2D,
+}
+
+/// A straight line between two coordinates, both included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment2D {
+    start: Coord2D,
+    end: Coord2D,
+}
+
+/// An axis-aligned rectangle of coordinates, including its edges.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect2D<T = u32> {
+    min: Coord2D<T>,
+    max: Coord2D<T>,
+}
+
+/// A closed polygon going through its vertices in order. Signed by default,
+/// as polygons are usually traced from an arbitrary starting point.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Polygon2D<T = i64> {
+    vertices: Vec<Coord2D<T>>,
+}
+
+macro_rules! impl_coord_num {
+    ($($t:ty => $signed:ty),*) => {$(
+        impl CoordNum for $t {
+            type Signed = $signed;
+
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            fn from_i128(n: i128) -> Option<Self> {
+                Self::try_from(n).ok()
+            }
+        }
+    )*};
+}
+
+impl_coord_num!(
+    u32 => i32,
+    u64 => i64,
+    usize => isize,
+    i32 => i32,
+    i64 => i64,
+    isize => isize
+);
+
+/// Converts back from `i128`, panicking on overflow like the primitive
+/// operators do.
+fn narrow<T: CoordNum>(n: i128) -> T {
+    T::from_i128(n).expect("coordinate overflows")
+}
+
+/// `|a - b|`, which always fits since no [`CoordNum`] is wider than 64 bits.
+fn abs_diff<T: CoordNum>(a: T, b: T) -> u64 {
+    a.to_i128().abs_diff(b.to_i128()) as u64
+}
+
+fn checked_add<T: CoordNum, D: CoordNum>(a: T, d: D) -> Option<T> {
+    T::from_i128(a.to_i128() + d.to_i128())
+}
+
 impl Direction2D {
     pub fn turn_right(&self) -> Direction2D {
         match self {
@@ -42,7 +182,130 @@ impl Direction2D {
         }
     }
 
-    pub fn to_offset(&self) -> CoordDiff2D {
+    pub fn turn_left(&self) -> Direction2D {
+        self.rotate_by(-1)
+    }
+
+    pub fn turn_around(&self) -> Direction2D {
+        self.rotate_by(2)
+    }
+
+    /// Turns `quarter_turns` times clockwise, or counterclockwise if it is
+    /// negative.
+    pub fn rotate_by(&self, quarter_turns: i32) -> Direction2D {
+        let clockwise = [Self::North, Self::East, Self::South, Self::West];
+        let current = clockwise.iter().position(|d| d == self).unwrap() as i32;
+        clockwise[(current + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    /// Returns the direction a single step of `offset` goes in, if there is
+    /// one.
+    pub fn from_offset(offset: CoordDiff2D) -> Option<Direction2D> {
+        Self::all().find(|d| d.to_offset() == offset)
+    }
+
+    /// Parses arrows (`^v<>`), compass points (`NSEW`) and `UDLR`.
+    pub fn from_char(c: char) -> Option<Direction2D> {
+        match c {
+            '^' | 'N' | 'U' => Some(Self::North),
+            'v' | 'S' | 'D' => Some(Self::South),
+            '<' | 'W' | 'L' => Some(Self::West),
+            '>' | 'E' | 'R' => Some(Self::East),
+            _ => None,
+        }
+    }
+
+    pub fn all() -> impl Iterator<Item = Self> {
+        [Self::North, Self::South, Self::East, Self::West]
+            .iter()
+            .cloned()
+    }
+}
+
+impl Direction8 {
+    const CLOCKWISE: [Direction8; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Turns a quarter turn clockwise, like [`Direction2D::turn_right`].
+    pub fn turn_right(&self) -> Direction8 {
+        self.rotate_by(2)
+    }
+
+    pub fn turn_left(&self) -> Direction8 {
+        self.rotate_by(-2)
+    }
+
+    pub fn turn_around(&self) -> Direction8 {
+        self.rotate_by(4)
+    }
+
+    /// Turns `eighth_turns` times by 45 degrees clockwise, or
+    /// counterclockwise if it is negative.
+    pub fn rotate_by(&self, eighth_turns: i32) -> Direction8 {
+        let current = Self::CLOCKWISE.iter().position(|d| d == self).unwrap() as i32;
+        Self::CLOCKWISE[(current + eighth_turns).rem_euclid(8) as usize]
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Self::NorthEast | Self::SouthEast | Self::SouthWest | Self::NorthWest
+        )
+    }
+
+    /// Returns the direction a single step of `offset` goes in, if there is
+    /// one.
+    pub fn from_offset(offset: CoordDiff2D) -> Option<Direction8> {
+        Self::all().find(|d| d.to_offset() == offset)
+    }
+
+    /// All directions, clockwise starting in the north.
+    pub fn all() -> impl Iterator<Item = Self> {
+        Self::CLOCKWISE.into_iter()
+    }
+
+    /// The four diagonal directions, clockwise starting in the north east.
+    pub fn diagonals() -> impl Iterator<Item = Self> {
+        Self::all().filter(Self::is_diagonal)
+    }
+}
+
+impl From<Direction2D> for Direction8 {
+    fn from(dir: Direction2D) -> Self {
+        match dir {
+            Direction2D::North => Self::North,
+            Direction2D::South => Self::South,
+            Direction2D::West => Self::West,
+            Direction2D::East => Self::East,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction2D {
+    type Error = Direction8;
+
+    /// Fails for diagonal directions.
+    fn try_from(dir: Direction8) -> Result<Self, Self::Error> {
+        match dir {
+            Direction8::North => Ok(Self::North),
+            Direction8::South => Ok(Self::South),
+            Direction8::West => Ok(Self::West),
+            Direction8::East => Ok(Self::East),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+impl ToOffset2D for Direction2D {
+    fn to_offset(&self) -> CoordDiff2D {
         match self {
             Direction2D::North => CoordDiff2D { dx: 0, dy: -1 },
             Direction2D::South => CoordDiff2D { dx: 0, dy: 1 },
@@ -50,11 +313,27 @@ impl Direction2D {
             Direction2D::East => CoordDiff2D { dx: 1, dy: 0 },
         }
     }
+}
 
-    pub fn all() -> impl Iterator<Item = Self> {
-        [Self::North, Self::South, Self::East, Self::West]
-            .iter()
-            .cloned()
+impl ToOffset2D for Direction8 {
+    fn to_offset(&self) -> CoordDiff2D {
+        let (dx, dy) = match self {
+            Self::North => (0, -1),
+            Self::NorthEast => (1, -1),
+            Self::East => (1, 0),
+            Self::SouthEast => (1, 1),
+            Self::South => (0, 1),
+            Self::SouthWest => (-1, 1),
+            Self::West => (-1, 0),
+            Self::NorthWest => (-1, -1),
+        };
+        CoordDiff2D { dx, dy }
+    }
+}
+
+impl ToOffset2D for CoordDiff2D {
+    fn to_offset(&self) -> CoordDiff2D {
+        *self
     }
 }
 
@@ -86,48 +365,254 @@ impl Bounds2D {
     pub fn is_valid(&self, coord: &Coord2D) -> bool {
         coord.x < self.width && coord.y < self.height
     }
+
+    /// All coordinates within the bounds that lie exactly on the line through
+    /// `a` and `b`, ordered from one edge to the other.
+    ///
+    /// Panics if `a == b`, which do not define a line.
+    pub fn line_through(&self, a: Coord2D, b: Coord2D) -> impl Iterator<Item = Coord2D> {
+        Ray2D::through(a, b).line_within(*self)
+    }
+
+    /// The same area as a rectangle, `None` if the bounds are empty.
+    pub fn rect(&self) -> Option<Rect2D> {
+        (self.width > 0 && self.height > 0).then(|| {
+            Rect2D::new(
+                Coord2D::new(0, 0),
+                Coord2D::new(self.width - 1, self.height - 1),
+            )
+        })
+    }
 }
 
-impl Coord2D {
-    pub fn new(x: u32, y: u32) -> Self {
+impl<T: CoordNum> Coord2D<T> {
+    pub fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
-    pub fn into_bounded(self, bounds: Bounds2D) -> BoundedCoord2D {
-        BoundedCoord2D::new(self, bounds)
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    pub fn y(&self) -> T {
+        self.y
     }
 
-    pub fn go_in(&self, dir: &Direction2D) -> Option<Coord2D> {
+    pub fn go_in(&self, dir: &Direction2D) -> Option<Self> {
         *self + dir.to_offset()
     }
+
+    /// Converts to another width, or `None` if a component does not fit.
+    pub fn try_cast<U: CoordNum>(self) -> Option<Coord2D<U>> {
+        Some(Coord2D::new(
+            U::from_i128(self.x.to_i128())?,
+            U::from_i128(self.y.to_i128())?,
+        ))
+    }
+
+    /// Converts to another width that can hold all values of this one.
+    pub fn cast<U: CoordNum + From<T>>(self) -> Coord2D<U> {
+        Coord2D::new(self.x.into(), self.y.into())
+    }
+
+    /// The difference `self - rhs`, or `None` if it does not fit into a
+    /// [`CoordDiff2D`].
+    pub fn checked_sub(self, rhs: Self) -> Option<CoordDiff2D<T::Signed>> {
+        Some(CoordDiff2D {
+            dx: T::Signed::from_i128(self.x.to_i128() - rhs.x.to_i128())?,
+            dy: T::Signed::from_i128(self.y.to_i128() - rhs.y.to_i128())?,
+        })
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// The number of steps between both coordinates if diagonal steps are
+    /// allowed.
+    pub fn chebyshev_distance(&self, other: &Self) -> u64 {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    pub fn euclidean_distance(&self, other: &Self) -> f64 {
+        (abs_diff(self.x, other.x) as f64).hypot(abs_diff(self.y, other.y) as f64)
+    }
 }
 
-impl Add<CoordDiff2D> for Coord2D {
+impl Coord2D {
+    pub fn into_bounded(self, bounds: Bounds2D) -> BoundedCoord2D {
+        BoundedCoord2D::new(self, bounds)
+    }
+}
+
+impl<T: CoordNum, D: CoordNum> Add<CoordDiff2D<D>> for Coord2D<T> {
     type Output = Option<Self>;
 
-    fn add(self, rhs: CoordDiff2D) -> Self::Output {
+    fn add(self, rhs: CoordDiff2D<D>) -> Self::Output {
         Some(Self::new(
-            self.x.checked_add_signed(rhs.dx)?,
-            self.y.checked_add_signed(rhs.dy)?,
+            checked_add(self.x, rhs.dx)?,
+            checked_add(self.y, rhs.dy)?,
         ))
     }
 }
 
-impl Sub for Coord2D {
-    type Output = CoordDiff2D;
+impl<T: CoordNum> Sub for Coord2D<T> {
+    type Output = CoordDiff2D<T::Signed>;
 
+    /// Panics if the difference does not fit into a [`CoordDiff2D`], see
+    /// [`Coord2D::checked_sub`].
     fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("coordinate difference overflows")
+    }
+}
+
+impl<T: CoordNum> CoordDiff2D<T> {
+    pub fn manhattan_len(&self) -> u64 {
+        self.dx.to_i128().unsigned_abs() as u64 + self.dy.to_i128().unsigned_abs() as u64
+    }
+
+    /// The number of steps needed if diagonal steps are allowed.
+    pub fn chebyshev_len(&self) -> u64 {
+        self.dx
+            .to_i128()
+            .unsigned_abs()
+            .max(self.dy.to_i128().unsigned_abs()) as u64
+    }
+
+    pub fn euclidean_len(&self) -> f64 {
+        (self.dx.to_i128() as f64).hypot(self.dy.to_i128() as f64)
+    }
+
+    /// The sign of both components, e.g. the single step in the general
+    /// direction of `self`.
+    pub fn signum(&self) -> Self {
+        Self {
+            dx: narrow(self.dx.to_i128().signum()),
+            dy: narrow(self.dy.to_i128().signum()),
+        }
+    }
+
+    /// Divides both components by their greatest common divisor, giving the
+    /// smallest step that reaches `self` when repeated. Zero stays zero.
+    pub fn reduced(&self) -> Self {
+        let (dx, dy) = (self.dx.to_i128(), self.dy.to_i128());
+        match gcd(dx.unsigned_abs() as u64, dy.unsigned_abs() as u64) {
+            0 => *self,
+            d => Self {
+                dx: narrow(dx / i128::from(d)),
+                dy: narrow(dy / i128::from(d)),
+            },
+        }
+    }
+
+    /// Rotates a quarter turn clockwise, like [`Direction2D::turn_right`].
+    pub fn rotate_cw(&self) -> Self {
+        Self {
+            dx: narrow(-self.dy.to_i128()),
+            dy: self.dx,
+        }
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        Self {
+            dx: self.dy,
+            dy: narrow(-self.dx.to_i128()),
+        }
+    }
+
+    pub fn dot(&self, other: &Self) -> i128 {
+        self.dx.to_i128() * other.dx.to_i128() + self.dy.to_i128() * other.dy.to_i128()
+    }
+
+    /// The z component of the cross product. Positive if `other` is clockwise
+    /// of `self`.
+    pub fn cross(&self, other: &Self) -> i128 {
+        self.dx.to_i128() * other.dy.to_i128() - self.dy.to_i128() * other.dx.to_i128()
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            dx: checked_add(self.dx, rhs.dx)?,
+            dy: checked_add(self.dy, rhs.dy)?,
+        })
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        Some(Self {
+            dx: T::from_i128(self.dx.to_i128() - rhs.dx.to_i128())?,
+            dy: T::from_i128(self.dy.to_i128() - rhs.dy.to_i128())?,
+        })
+    }
+
+    pub fn checked_mul(self, rhs: T) -> Option<Self> {
+        Some(Self {
+            dx: T::from_i128(self.dx.to_i128() * rhs.to_i128())?,
+            dy: T::from_i128(self.dy.to_i128() * rhs.to_i128())?,
+        })
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            dx: T::from_i128(-self.dx.to_i128())?,
+            dy: T::from_i128(-self.dy.to_i128())?,
+        })
+    }
+
+    /// Converts to another width, or `None` if a component does not fit.
+    pub fn try_cast<U: CoordNum>(self) -> Option<CoordDiff2D<U>> {
+        Some(CoordDiff2D {
+            dx: U::from_i128(self.dx.to_i128())?,
+            dy: U::from_i128(self.dy.to_i128())?,
+        })
+    }
+
+    /// Converts to another width that can hold all values of this one.
+    pub fn cast<U: CoordNum + From<T>>(self) -> CoordDiff2D<U> {
         CoordDiff2D {
-            dx: i32::try_from(self.x).unwrap() - i32::try_from(rhs.x).unwrap(),
-            dy: i32::try_from(self.y).unwrap() - i32::try_from(rhs.y).unwrap(),
+            dx: self.dx.into(),
+            dy: self.dy.into(),
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for CoordDiff2D<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            dx: self.dx + rhs.dx,
+            dy: self.dy + rhs.dy,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for CoordDiff2D<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            dx: self.dx - rhs.dx,
+            dy: self.dy - rhs.dy,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for CoordDiff2D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            dx: -self.dx,
+            dy: -self.dy,
         }
     }
 }
 
-impl Mul<i32> for CoordDiff2D {
+impl<T: Mul<Output = T> + Copy> Mul<T> for CoordDiff2D<T> {
     type Output = Self;
 
-    fn mul(self, rhs: i32) -> Self::Output {
+    fn mul(self, rhs: T) -> Self::Output {
         Self {
             dx: self.dx * rhs,
             dy: self.dy * rhs,
@@ -135,48 +620,1108 @@ impl Mul<i32> for CoordDiff2D {
     }
 }
 
-impl BoundedCoord2D {
-    pub fn new(coord: Coord2D, bounds: Bounds2D) -> Self {
-        if coord.x >= bounds.width || coord.y >= bounds.height {
-            panic!("coordinates out-of-bounds");
+impl<T: Div<Output = T> + Copy> Div<T> for CoordDiff2D<T> {
+    type Output = Self;
+
+    /// Divides both components, rounding towards zero.
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            dx: self.dx / rhs,
+            dy: self.dy / rhs,
         }
-        Self { coord, bounds }
     }
+}
 
-    pub fn unbounded(&self) -> Coord2D {
-        self.coord
+impl<T: CoordNum> Coord3D<T> {
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
     }
 
-    pub fn bounds(&self) -> Bounds2D {
-        self.bounds
+    pub fn x(&self) -> T {
+        self.x
     }
 
-    pub fn go_in(&self, dir: &Direction2D) -> Option<BoundedCoord2D> {
-        let new = self.coord.go_in(dir)?;
-        Self::if_valid(self.bounds(), new)
+    pub fn y(&self) -> T {
+        self.y
     }
 
-    fn if_valid(bounds: Bounds2D, coord: Coord2D) -> Option<BoundedCoord2D> {
-        if bounds.is_valid(&coord) {
-            Some(Self::new(coord, bounds))
-        } else {
-            None
-        }
+    pub fn z(&self) -> T {
+        self.z
+    }
+
+    /// The up to six positions sharing a face with this one.
+    pub fn neighbors6(&self) -> impl Iterator<Item = Self> + use<T> {
+        let this = *self;
+        CoordDiff3D::axes().filter_map(move |d| this + d)
+    }
+
+    /// Converts to another width, or `None` if a component does not fit.
+    pub fn try_cast<U: CoordNum>(self) -> Option<Coord3D<U>> {
+        Some(Coord3D::new(
+            U::from_i128(self.x.to_i128())?,
+            U::from_i128(self.y.to_i128())?,
+            U::from_i128(self.z.to_i128())?,
+        ))
+    }
+
+    /// Converts to another width that can hold all values of this one.
+    pub fn cast<U: CoordNum + From<T>>(self) -> Coord3D<U> {
+        Coord3D::new(self.x.into(), self.y.into(), self.z.into())
+    }
+
+    /// The difference `self - rhs`, or `None` if it does not fit into a
+    /// [`CoordDiff3D`].
+    pub fn checked_sub(self, rhs: Self) -> Option<CoordDiff3D<T::Signed>> {
+        Some(CoordDiff3D {
+            dx: T::Signed::from_i128(self.x.to_i128() - rhs.x.to_i128())?,
+            dy: T::Signed::from_i128(self.y.to_i128() - rhs.y.to_i128())?,
+            dz: T::Signed::from_i128(self.z.to_i128() - rhs.z.to_i128())?,
+        })
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> u64 {
+        abs_diff(self.x, other.x)
+            .max(abs_diff(self.y, other.y))
+            .max(abs_diff(self.z, other.z))
     }
 }
 
-impl Add<CoordDiff2D> for BoundedCoord2D {
+impl<T: CoordNum, D: CoordNum> Add<CoordDiff3D<D>> for Coord3D<T> {
     type Output = Option<Self>;
 
-    fn add(self, rhs: CoordDiff2D) -> Self::Output {
-        Self::if_valid(self.bounds(), (self.coord + rhs)?)
+    fn add(self, rhs: CoordDiff3D<D>) -> Self::Output {
+        Some(Self::new(
+            checked_add(self.x, rhs.dx)?,
+            checked_add(self.y, rhs.dy)?,
+            checked_add(self.z, rhs.dz)?,
+        ))
     }
 }
 
-impl Sub for BoundedCoord2D {
-    type Output = CoordDiff2D;
+impl<T: CoordNum> Sub for Coord3D<T> {
+    type Output = CoordDiff3D<T::Signed>;
 
+    /// Panics if the difference does not fit into a [`CoordDiff3D`], see
+    /// [`Coord3D::checked_sub`].
     fn sub(self, rhs: Self) -> Self::Output {
-        self.coord - rhs.coord
+        self.checked_sub(rhs)
+            .expect("coordinate difference overflows")
+    }
+}
+
+impl CoordDiff3D {
+    /// The six unit steps along the axes.
+    pub fn axes() -> impl Iterator<Item = Self> {
+        [
+            (1, 0, 0),
+            (-1, 0, 0),
+            (0, 1, 0),
+            (0, -1, 0),
+            (0, 0, 1),
+            (0, 0, -1),
+        ]
+        .into_iter()
+        .map(|(dx, dy, dz)| Self { dx, dy, dz })
+    }
+}
+
+impl<T: CoordNum> CoordDiff3D<T> {
+    pub fn manhattan_len(&self) -> u64 {
+        [self.dx, self.dy, self.dz]
+            .iter()
+            .map(|d| d.to_i128().unsigned_abs() as u64)
+            .sum()
+    }
+
+    pub fn dot(&self, other: &Self) -> i128 {
+        self.dx.to_i128() * other.dx.to_i128()
+            + self.dy.to_i128() * other.dy.to_i128()
+            + self.dz.to_i128() * other.dz.to_i128()
+    }
+
+    /// The vector orthogonal to both `self` and `other`.
+    pub fn cross(&self, other: &Self) -> Self {
+        let [ax, ay, az] = [self.dx, self.dy, self.dz].map(CoordNum::to_i128);
+        let [bx, by, bz] = [other.dx, other.dy, other.dz].map(CoordNum::to_i128);
+        Self {
+            dx: narrow(ay * bz - az * by),
+            dy: narrow(az * bx - ax * bz),
+            dz: narrow(ax * by - ay * bx),
+        }
+    }
+}
+
+impl<T: Add<Output = T>> Add for CoordDiff3D<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            dx: self.dx + rhs.dx,
+            dy: self.dy + rhs.dy,
+            dz: self.dz + rhs.dz,
+        }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for CoordDiff3D<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            dx: self.dx - rhs.dx,
+            dy: self.dy - rhs.dy,
+            dz: self.dz - rhs.dz,
+        }
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for CoordDiff3D<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            dx: -self.dx,
+            dy: -self.dy,
+            dz: -self.dz,
+        }
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for CoordDiff3D<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            dx: self.dx * rhs,
+            dy: self.dy * rhs,
+            dz: self.dz * rhs,
+        }
+    }
+}
+
+impl<const N: usize, T: CoordNum> Point<N, T> {
+    pub fn new(coords: [T; N]) -> Self {
+        Self { coords }
+    }
+
+    pub fn coords(&self) -> [T; N] {
+        self.coords
+    }
+
+    /// The up to `2 * N` positions differing by one in a single component.
+    pub fn neighbors(&self) -> impl Iterator<Item = Self> + use<N, T> {
+        let coords = self.coords;
+        (0..N).flat_map(move |axis| {
+            [-1, 1].into_iter().filter_map(move |d| {
+                let mut coords = coords;
+                coords[axis] = checked_add(coords[axis], d)?;
+                Some(Self { coords })
+            })
+        })
+    }
+
+    /// Converts to another width, or `None` if a component does not fit.
+    pub fn try_cast<U: CoordNum>(self) -> Option<Point<N, U>> {
+        let coords = self.coords.map(|c| U::from_i128(c.to_i128()));
+        if coords.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(Point::new(coords.map(Option::unwrap)))
+    }
+
+    /// Converts to another width that can hold all values of this one.
+    pub fn cast<U: CoordNum + From<T>>(self) -> Point<N, U> {
+        Point::new(self.coords.map(U::from))
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> u64 {
+        (0..N).map(|i| abs_diff(self[i], other[i])).sum()
+    }
+
+    pub fn chebyshev_distance(&self, other: &Self) -> u64 {
+        (0..N)
+            .map(|i| abs_diff(self[i], other[i]))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl<const N: usize, T> Index<usize> for Point<N, T> {
+    type Output = T;
+
+    fn index(&self, axis: usize) -> &Self::Output {
+        &self.coords[axis]
+    }
+}
+
+impl<const N: usize, T: Add<Output = T> + Copy> Add for Point<N, T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            coords: std::array::from_fn(|i| self.coords[i] + rhs.coords[i]),
+        }
+    }
+}
+
+impl<const N: usize, T: Sub<Output = T> + Copy> Sub for Point<N, T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            coords: std::array::from_fn(|i| self.coords[i] - rhs.coords[i]),
+        }
+    }
+}
+
+impl<const N: usize, T> From<[T; N]> for Point<N, T> {
+    fn from(coords: [T; N]) -> Self {
+        Self { coords }
+    }
+}
+
+impl<T> From<Coord2D<T>> for Point<2, T> {
+    fn from(coord: Coord2D<T>) -> Self {
+        Self {
+            coords: [coord.x, coord.y],
+        }
+    }
+}
+
+impl<T> From<Coord3D<T>> for Point<3, T> {
+    fn from(coord: Coord3D<T>) -> Self {
+        Self {
+            coords: [coord.x, coord.y, coord.z],
+        }
+    }
+}
+
+impl BoundedCoord2D {
+    pub fn new(coord: Coord2D, bounds: Bounds2D) -> Self {
+        if coord.x >= bounds.width || coord.y >= bounds.height {
+            panic!("coordinates out-of-bounds");
+        }
+        Self { coord, bounds }
+    }
+
+    pub fn unbounded(&self) -> Coord2D {
+        self.coord
+    }
+
+    pub fn bounds(&self) -> Bounds2D {
+        self.bounds
+    }
+
+    pub fn go_in(&self, dir: &Direction2D) -> Option<BoundedCoord2D> {
+        let new = self.coord.go_in(dir)?;
+        Self::if_valid(self.bounds(), new)
+    }
+
+    fn if_valid(bounds: Bounds2D, coord: Coord2D) -> Option<BoundedCoord2D> {
+        if bounds.is_valid(&coord) {
+            Some(Self::new(coord, bounds))
+        } else {
+            None
+        }
+    }
+}
+
+impl Add<CoordDiff2D> for BoundedCoord2D {
+    type Output = Option<Self>;
+
+    fn add(self, rhs: CoordDiff2D) -> Self::Output {
+        Self::if_valid(self.bounds(), (self.coord + rhs)?)
+    }
+}
+
+impl Sub for BoundedCoord2D {
+    type Output = CoordDiff2D;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.coord - rhs.coord
+    }
+}
+
+impl WrappingCoord2D {
+    pub fn new(coord: Coord2D, bounds: Bounds2D) -> Self {
+        if !bounds.is_valid(&coord) {
+            panic!("coordinates out-of-bounds");
+        }
+        Self { coord, bounds }
+    }
+
+    pub fn unbounded(&self) -> Coord2D {
+        self.coord
+    }
+
+    pub fn bounds(&self) -> Bounds2D {
+        self.bounds
+    }
+
+    pub fn go_in(&self, dir: &Direction2D) -> WrappingCoord2D {
+        *self + dir.to_offset()
+    }
+}
+
+impl Add<CoordDiff2D> for WrappingCoord2D {
+    type Output = Self;
+
+    fn add(self, rhs: CoordDiff2D) -> Self::Output {
+        let wrap = |v: u32, d: i32, size: u32| {
+            (i64::from(v) + i64::from(d)).rem_euclid(i64::from(size)) as u32
+        };
+        Self {
+            coord: Coord2D::new(
+                wrap(self.coord.x, rhs.dx, self.bounds.width),
+                wrap(self.coord.y, rhs.dy, self.bounds.height),
+            ),
+            bounds: self.bounds,
+        }
+    }
+}
+
+impl Sub for WrappingCoord2D {
+    type Output = CoordDiff2D;
+
+    /// The difference without wrapping around.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.coord - rhs.coord
+    }
+}
+
+impl Ray2D {
+    /// Panics if `step` is zero, which would never leave `origin`.
+    pub fn new(origin: Coord2D, step: CoordDiff2D) -> Self {
+        if step == (CoordDiff2D { dx: 0, dy: 0 }) {
+            panic!("ray without direction");
+        }
+        Self { origin, step }
+    }
+
+    /// The ray from `a` hitting every coordinate on the line towards `b`.
+    pub fn through(a: Coord2D, b: Coord2D) -> Self {
+        Self::new(a, (b - a).reduced())
+    }
+
+    pub fn origin(&self) -> Coord2D {
+        self.origin
+    }
+
+    pub fn step(&self) -> CoordDiff2D {
+        self.step
+    }
+
+    pub fn reversed(&self) -> Self {
+        Self::new(self.origin, -self.step)
+    }
+
+    /// The coordinates on the ray, starting with `origin`, until they leave
+    /// the representable range.
+    pub fn points(&self) -> impl Iterator<Item = Coord2D> {
+        let step = self.step;
+        successors(Some(self.origin), move |&p| p + step)
+    }
+
+    /// The coordinates on the ray until it leaves `bounds`.
+    pub fn points_within(&self, bounds: Bounds2D) -> impl Iterator<Item = Coord2D> {
+        self.points().take_while(move |p| bounds.is_valid(p))
+    }
+
+    /// The coordinates on the ray extended in both directions, from the edge
+    /// of `bounds` behind `origin` to the edge in front of it. Empty if
+    /// `origin` is out of bounds.
+    pub fn line_within(&self, bounds: Bounds2D) -> impl Iterator<Item = Coord2D> {
+        let (start, step) = (self.reversed().points_within(bounds).last(), self.step);
+        start
+            .into_iter()
+            .flat_map(move |start| Self::new(start, step).points_within(bounds))
+    }
+}
+
+impl Segment2D {
+    pub fn new(start: Coord2D, end: Coord2D) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> Coord2D {
+        self.start
+    }
+
+    pub fn end(&self) -> Coord2D {
+        self.end
+    }
+
+    /// Whether `point` lies exactly on the segment.
+    pub fn contains(&self, point: &Coord2D) -> bool {
+        let between = |p: u32, a: u32, b: u32| a.min(b) <= p && p <= a.max(b);
+        (self.end - self.start).cross(&(*point - self.start)) == 0
+            && between(point.x, self.start.x, self.end.x)
+            && between(point.y, self.start.y, self.end.y)
+    }
+
+    /// The coordinates lying exactly on the segment, from `start` to `end`.
+    pub fn lattice_points(&self) -> impl Iterator<Item = Coord2D> {
+        let diff = self.end - self.start;
+        let step = diff.reduced();
+        let count = match step.chebyshev_len() {
+            0 => 1,
+            len => diff.chebyshev_len() / len + 1,
+        };
+        successors(Some(self.start), move |&p| p + step).take(count as usize)
+    }
+
+    /// The coordinates closest to the segment, from `start` to `end`, with
+    /// every step going to one of the eight neighbors (Bresenham's line
+    /// algorithm).
+    pub fn rasterize(&self) -> impl Iterator<Item = Coord2D> {
+        let diff = self.end - self.start;
+        let sign = diff.signum();
+        let (dx, dy) = (
+            i64::from(diff.dx.unsigned_abs()),
+            -i64::from(diff.dy.unsigned_abs()),
+        );
+        let mut error = dx + dy;
+        let mut next = Some(self.start);
+        let end = self.end;
+
+        from_fn(move || {
+            let current = next?;
+            next = (current != end).then(|| {
+                let mut step = CoordDiff2D { dx: 0, dy: 0 };
+                if 2 * error >= dy {
+                    step.dx = sign.dx;
+                }
+                if 2 * error <= dx {
+                    step.dy = sign.dy;
+                }
+                error += i64::from(step.dx.abs()) * dy + i64::from(step.dy.abs()) * dx;
+                (current + step).unwrap()
+            });
+            Some(current)
+        })
+    }
+
+    pub fn intersects(&self, other: &Segment2D) -> bool {
+        self.crossing(other).is_some() || self.shared_end_points(other).next().is_some()
+    }
+
+    /// The single coordinate both segments meet at, or `None` if they don't
+    /// meet, meet between coordinates or overlap on more than one point.
+    pub fn intersection(&self, other: &Segment2D) -> Option<Coord2D> {
+        if let Some((t, denom)) = self.crossing(other) {
+            let diff = self.end - self.start;
+            let (dx, dy) = (i128::from(diff.dx) * t, i128::from(diff.dy) * t);
+            if dx % denom != 0 || dy % denom != 0 {
+                return None;
+            }
+            let step: CoordDiff2D = CoordDiff2D {
+                dx: narrow(dx / denom),
+                dy: narrow(dy / denom),
+            };
+            return self.start + step;
+        }
+        let mut shared = self.shared_end_points(other);
+        let point = shared.next()?;
+        shared.next().is_none().then_some(point)
+    }
+
+    /// Where the segments cross, as the fraction `t / denom` of the way from
+    /// `self.start` to `self.end`. `None` for parallel segments.
+    fn crossing(&self, other: &Segment2D) -> Option<(i128, i128)> {
+        let (r, s) = (self.end - self.start, other.end - other.start);
+        let denom = r.cross(&s);
+        if denom == 0 {
+            return None;
+        }
+        let offset = other.start - self.start;
+        let (t, u) = (offset.cross(&s), offset.cross(&r));
+        let on_segment = |n: i128| (0..=denom.abs()).contains(&(n * denom.signum()));
+        (on_segment(t) && on_segment(u)).then_some((t, denom))
+    }
+
+    fn shared_end_points<'a>(&'a self, other: &'a Segment2D) -> impl Iterator<Item = Coord2D> + 'a {
+        [self.start, self.end]
+            .into_iter()
+            .filter(|p| other.contains(p))
+            .chain(
+                [other.start, other.end]
+                    .into_iter()
+                    .filter(|p| self.contains(p)),
+            )
+            .unique()
+    }
+}
+
+impl<T: CoordNum> Rect2D<T> {
+    /// The rectangle spanned by two opposite corners.
+    pub fn new(a: Coord2D<T>, b: Coord2D<T>) -> Self {
+        Self {
+            min: Coord2D::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Coord2D::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// The bounding box of `points`, `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Coord2D<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        Some(points.fold(Self::new(first, first), |rect, p| {
+            rect.union(&Self::new(p, p))
+        }))
+    }
+
+    pub fn min(&self) -> Coord2D<T> {
+        self.min
+    }
+
+    pub fn max(&self) -> Coord2D<T> {
+        self.max
+    }
+
+    pub fn width(&self) -> u64 {
+        abs_diff(self.min.x, self.max.x) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        abs_diff(self.min.y, self.max.y) + 1
+    }
+
+    /// The number of coordinates in the rectangle.
+    pub fn area(&self) -> u64 {
+        self.width() * self.height()
+    }
+
+    /// The four corners, clockwise starting at `min`.
+    pub fn corners(&self) -> [Coord2D<T>; 4] {
+        [
+            self.min,
+            Coord2D::new(self.max.x, self.min.y),
+            self.max,
+            Coord2D::new(self.min.x, self.max.y),
+        ]
+    }
+
+    pub fn contains(&self, coord: &Coord2D<T>) -> bool {
+        (self.min.x..=self.max.x).contains(&coord.x) && (self.min.y..=self.max.y).contains(&coord.y)
+    }
+
+    /// The smallest rectangle containing both.
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            min: Coord2D::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Coord2D::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    /// The coordinates in both rectangles, `None` if they don't overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let min = Coord2D::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Coord2D::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+        (min.x <= max.x && min.y <= max.y).then_some(Self { min, max })
+    }
+
+    /// All coordinates in the rectangle, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Coord2D<T>> + use<T> {
+        let (min, max) = (self.min, self.max);
+        (min.y.to_i128()..=max.y.to_i128()).flat_map(move |y| {
+            (min.x.to_i128()..=max.x.to_i128()).map(move |x| Coord2D::new(narrow(x), narrow(y)))
+        })
+    }
+}
+
+impl<T: CoordNum> Polygon2D<T> {
+    pub fn new(vertices: Vec<Coord2D<T>>) -> Self {
+        Self { vertices }
+    }
+
+    pub fn vertices(&self) -> &[Coord2D<T>] {
+        &self.vertices
+    }
+
+    /// Twice the area, which is always an integer (shoelace formula).
+    /// Positive if the vertices go clockwise with y pointing down, negative
+    /// if they go counterclockwise.
+    pub fn doubled_signed_area(&self) -> i128 {
+        self.edges()
+            .map(|(a, b)| a.x.to_i128() * b.y.to_i128() - b.x.to_i128() * a.y.to_i128())
+            .sum()
+    }
+
+    pub fn area(&self) -> f64 {
+        self.doubled_signed_area().unsigned_abs() as f64 / 2.0
+    }
+
+    pub fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.euclidean_distance(&b)).sum()
+    }
+
+    /// The number of coordinates on the edges. Same as the perimeter if all
+    /// edges are axis-aligned.
+    pub fn boundary_points(&self) -> u64 {
+        self.edges()
+            .map(|(a, b)| gcd(abs_diff(a.x, b.x), abs_diff(a.y, b.y)))
+            .sum()
+    }
+
+    /// The number of coordinates strictly inside, by Pick's theorem. Only
+    /// meaningful if the edges do not cross each other.
+    pub fn interior_points(&self) -> u64 {
+        let doubled = self.doubled_signed_area().abs() - i128::from(self.boundary_points()) + 2;
+        u64::try_from(doubled / 2).unwrap_or(0)
+    }
+
+    /// The number of coordinates inside or on the edges.
+    pub fn enclosed_points(&self) -> u64 {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn bounding_box(&self) -> Option<Rect2D<T>> {
+        Rect2D::from_points(self.vertices.iter().copied())
+    }
+
+    fn edges(&self) -> impl Iterator<Item = (Coord2D<T>, Coord2D<T>)> + '_ {
+        self.vertices.iter().copied().circular_tuple_windows()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, iter::successors};
+
+    use proptest::prelude::*;
+
+    use crate::coord::{
+        Bounds2D, Coord2D, Coord3D, CoordDiff2D, CoordDiff3D, Direction2D, Direction8, Point,
+        Polygon2D, Ray2D, Rect2D, Segment2D, ToOffset2D, WrappingCoord2D,
+    };
+
+    fn direction() -> impl Strategy<Value = Direction2D> {
+        prop::sample::select(Direction2D::all().collect::<Vec<_>>())
+    }
+
+    fn coord() -> impl Strategy<Value = Coord2D> {
+        (0..i32::MAX as u32, 0..i32::MAX as u32).prop_map(|(x, y)| Coord2D::new(x, y))
+    }
+
+    #[test]
+    fn test_direction2d() {
+        assert_eq!(Direction2D::North.turn_left(), Direction2D::West);
+        assert_eq!(Direction2D::East.turn_around(), Direction2D::West);
+        assert_eq!(Direction2D::South.rotate_by(-3), Direction2D::West);
+        assert_eq!(
+            Direction2D::from_offset(CoordDiff2D { dx: 0, dy: 1 }),
+            Some(Direction2D::South)
+        );
+        assert_eq!(Direction2D::from_offset(CoordDiff2D { dx: 1, dy: 1 }), None);
+        assert_eq!(
+            "^v<>NSWEUDLR"
+                .chars()
+                .map(|c| Direction2D::from_char(c).unwrap())
+                .collect::<Vec<_>>(),
+            [
+                Direction2D::North,
+                Direction2D::South,
+                Direction2D::West,
+                Direction2D::East
+            ]
+            .repeat(3)
+        );
+        assert_eq!(Direction2D::from_char('x'), None);
+    }
+
+    #[test]
+    fn test_direction8() {
+        assert_eq!(Direction8::North.rotate_by(1), Direction8::NorthEast);
+        assert_eq!(Direction8::NorthEast.turn_right(), Direction8::SouthEast);
+        assert_eq!(Direction8::NorthWest.turn_left(), Direction8::SouthWest);
+        assert_eq!(Direction8::SouthEast.turn_around(), Direction8::NorthWest);
+        assert_eq!(Direction8::West.rotate_by(-9), Direction8::SouthWest);
+        assert_eq!(Direction8::diagonals().count(), 4);
+        assert_eq!(
+            Direction8::from_offset(CoordDiff2D { dx: -1, dy: 1 }),
+            Some(Direction8::SouthWest)
+        );
+        assert_eq!(Direction8::from_offset(CoordDiff2D { dx: 2, dy: 0 }), None);
+        assert_eq!(Direction8::from(Direction2D::East), Direction8::East);
+        assert_eq!(
+            Direction2D::try_from(Direction8::NorthEast),
+            Err(Direction8::NorthEast)
+        );
+    }
+
+    #[test]
+    fn test_diff_arithmetic() {
+        let a = CoordDiff2D { dx: 4, dy: -6 };
+        let b = CoordDiff2D { dx: 1, dy: 2 };
+        assert_eq!(a + b, CoordDiff2D { dx: 5, dy: -4 });
+        assert_eq!(a - b, CoordDiff2D { dx: 3, dy: -8 });
+        assert_eq!(-a, CoordDiff2D { dx: -4, dy: 6 });
+        assert_eq!(a / 3, CoordDiff2D { dx: 1, dy: -2 });
+        assert_eq!(a.reduced(), CoordDiff2D { dx: 2, dy: -3 });
+        assert_eq!(
+            CoordDiff2D { dx: 0, dy: -5 }.reduced(),
+            Direction2D::North.to_offset()
+        );
+        assert_eq!(
+            CoordDiff2D { dx: 0, dy: 0 }.reduced(),
+            CoordDiff2D { dx: 0, dy: 0 }
+        );
+        assert_eq!(a.signum(), CoordDiff2D { dx: 1, dy: -1 });
+        assert_eq!((a.manhattan_len(), a.chebyshev_len()), (10, 6));
+        assert_eq!(CoordDiff2D { dx: 3, dy: 4 }.euclidean_len(), 5.0);
+        assert_eq!(a.dot(&b), -8);
+        assert_eq!(a.cross(&b), 14);
+        assert_eq!(b.rotate_cw().rotate_ccw(), b);
+
+        let max = CoordDiff2D {
+            dx: i32::MAX,
+            dy: 0,
+        };
+        assert_eq!(max.checked_add(b), None);
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(
+            max.checked_neg(),
+            Some(CoordDiff2D {
+                dx: -i32::MAX,
+                dy: 0
+            })
+        );
+        assert_eq!((-max).checked_sub(b * 2), None);
+    }
+
+    #[test]
+    fn test_coord_distances() {
+        let a = Coord2D::new(1, 7);
+        let b = Coord2D::new(4, 3);
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!(a.chebyshev_distance(&b), 4);
+        assert_eq!(a.euclidean_distance(&b), 5.0);
+        assert_eq!(a.checked_sub(b), Some(CoordDiff2D { dx: -3, dy: 4 }));
+        assert_eq!(Coord2D::new(u32::MAX, 0).checked_sub(a), None);
+    }
+
+    #[test]
+    fn test_widths() {
+        let big = Coord2D::<i64>::new(-5_000_000_000, 3);
+        let diff = big - Coord2D::new(0, 0);
+        assert_eq!(diff.dx, -5_000_000_000);
+        assert_eq!(
+            big.manhattan_distance(&Coord2D::new(5_000_000_000, 0)),
+            10_000_000_003
+        );
+        assert_eq!(big.try_cast::<u32>(), None);
+        assert_eq!(big.try_cast::<i32>(), None);
+
+        let small = Coord2D::new(7u32, 2);
+        assert_eq!(small.cast::<u64>(), Coord2D::new(7, 2));
+        assert_eq!(small.try_cast::<usize>(), Some(Coord2D::new(7, 2)));
+        assert_eq!(
+            Coord2D::<usize>::new(0, 0) + CoordDiff2D { dx: 1, dy: 2 },
+            Some(Coord2D::new(1, 2))
+        );
+        assert_eq!(Coord2D::<usize>::new(0, 0).go_in(&Direction2D::North), None);
+        assert_eq!(
+            Coord2D::<u64>::new(u64::MAX, 0).checked_sub(Coord2D::new(0, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_3d() {
+        let a = Coord3D::new(1, 2, 3);
+        let b = Coord3D::new(-1, 4, 3);
+        assert_eq!(
+            a - b,
+            CoordDiff3D {
+                dx: 2,
+                dy: -2,
+                dz: 0
+            }
+        );
+        assert_eq!(b + (a - b), Some(a));
+        assert_eq!(a.manhattan_distance(&b), 4);
+        assert_eq!(a.chebyshev_distance(&b), 2);
+        assert_eq!(a.neighbors6().count(), 6);
+        assert_eq!(Coord3D::<u32>::new(0, 0, 5).neighbors6().count(), 4);
+
+        let x = CoordDiff3D {
+            dx: 1,
+            dy: 0,
+            dz: 0,
+        };
+        let y = CoordDiff3D {
+            dx: 0,
+            dy: 1,
+            dz: 0,
+        };
+        assert_eq!(
+            x.cross(&y),
+            CoordDiff3D {
+                dx: 0,
+                dy: 0,
+                dz: 1
+            }
+        );
+        assert_eq!(x.dot(&y), 0);
+        assert_eq!((x * 3 - y).manhattan_len(), 4);
+    }
+
+    #[test]
+    fn test_point() {
+        let p = Point::new([1, 2, 3, 4]);
+        let q = Point::from([0, 0, 0, 0]);
+        assert_eq!(p[2], 3);
+        assert_eq!(p.manhattan_distance(&q), 10);
+        assert_eq!(p.chebyshev_distance(&q), 4);
+        assert_eq!(p - p, q);
+        assert_eq!(p + q, p);
+        assert_eq!(p.neighbors().count(), 8);
+        assert!(p.neighbors().all(|n| n.manhattan_distance(&p) == 1));
+        assert_eq!(Point::<2, u32>::new([0, 0]).neighbors().count(), 2);
+        assert_eq!(Point::from(Coord2D::new(3u32, 4)).coords(), [3, 4]);
+        assert_eq!(Point::new([-1, 2]).try_cast::<u32>(), None);
+        assert_eq!(
+            Point::from(Coord3D::new(1, 2, 3)).try_cast::<u32>(),
+            Some(Point::new([1, 2, 3]))
+        );
+    }
+
+    #[test]
+    fn test_ray() {
+        let bounds = Bounds2D::new(10, 10);
+        let ray = Ray2D::through(Coord2D::new(3, 4), Coord2D::new(5, 5));
+        assert_eq!(ray.step(), CoordDiff2D { dx: 2, dy: 1 });
+        assert_eq!(
+            ray.points_within(bounds).collect::<Vec<_>>(),
+            [(3, 4), (5, 5), (7, 6), (9, 7)].map(|(x, y)| Coord2D::new(x, y))
+        );
+        assert_eq!(
+            ray.line_within(bounds).collect::<Vec<_>>(),
+            [(1, 3), (3, 4), (5, 5), (7, 6), (9, 7)].map(|(x, y)| Coord2D::new(x, y))
+        );
+        assert_eq!(ray.reversed().points().count(), 2);
+        assert_eq!(ray.line_within(Bounds2D::new(3, 3)).count(), 0);
+        assert_eq!(
+            bounds
+                .line_through(Coord2D::new(0, 0), Coord2D::new(4, 4))
+                .count(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_segment() {
+        let segment = Segment2D::new(Coord2D::new(0, 0), Coord2D::new(6, 3));
+        assert_eq!(
+            segment.lattice_points().collect::<Vec<_>>(),
+            [(0, 0), (2, 1), (4, 2), (6, 3)].map(|(x, y)| Coord2D::new(x, y))
+        );
+        assert!(segment.contains(&Coord2D::new(4, 2)));
+        assert!(!segment.contains(&Coord2D::new(8, 4)));
+        assert!(!segment.contains(&Coord2D::new(3, 1)));
+
+        let steep = Segment2D::new(Coord2D::new(4, 2), Coord2D::new(0, 0));
+        assert_eq!(
+            steep.rasterize().collect::<Vec<_>>(),
+            [(4, 2), (3, 1), (2, 1), (1, 0), (0, 0)].map(|(x, y)| Coord2D::new(x, y))
+        );
+        let point = Segment2D::new(Coord2D::new(1, 1), Coord2D::new(1, 1));
+        assert_eq!(point.rasterize().count(), 1);
+        assert_eq!(point.lattice_points().count(), 1);
+    }
+
+    #[test]
+    fn test_intersection() {
+        let segment =
+            |(x1, y1), (x2, y2)| Segment2D::new(Coord2D::new(x1, y1), Coord2D::new(x2, y2));
+        let a = segment((0, 0), (4, 4));
+
+        assert_eq!(
+            a.intersection(&segment((0, 4), (4, 0))),
+            Some(Coord2D::new(2, 2))
+        );
+        assert_eq!(a.intersection(&segment((0, 3), (3, 0))), None);
+        assert!(a.intersects(&segment((0, 3), (3, 0))));
+        assert!(!a.intersects(&segment((0, 5), (1, 4))));
+
+        assert_eq!(
+            a.intersection(&segment((4, 4), (6, 6))),
+            Some(Coord2D::new(4, 4))
+        );
+        assert_eq!(a.intersection(&segment((2, 2), (6, 6))), None);
+        assert!(a.intersects(&segment((2, 2), (6, 6))));
+        assert!(!a.intersects(&segment((1, 0), (5, 4))));
+        assert_eq!(
+            a.intersection(&segment((3, 3), (3, 3))),
+            Some(Coord2D::new(3, 3))
+        );
+    }
+
+    #[test]
+    fn test_rect() {
+        let rect = Rect2D::new(Coord2D::new(4, 1), Coord2D::new(1, 3));
+        assert_eq!(
+            (rect.min(), rect.max()),
+            (Coord2D::new(1, 1), Coord2D::new(4, 3))
+        );
+        assert_eq!((rect.width(), rect.height(), rect.area()), (4, 3, 12));
+        assert_eq!(rect.positions().count(), 12);
+        assert!(rect.positions().all(|p| rect.contains(&p)));
+        assert!(!rect.contains(&Coord2D::new(0, 2)));
+
+        let other = Rect2D::new(Coord2D::new(3, 0), Coord2D::new(6, 1));
+        assert_eq!(
+            rect.union(&other),
+            Rect2D::new(Coord2D::new(1, 0), Coord2D::new(6, 3))
+        );
+        assert_eq!(
+            rect.intersection(&other),
+            Some(Rect2D::new(Coord2D::new(3, 1), Coord2D::new(4, 1)))
+        );
+        assert_eq!(
+            rect.intersection(&Rect2D::new(Coord2D::new(5, 0), Coord2D::new(5, 9))),
+            None
+        );
+
+        assert_eq!(Rect2D::from_points(rect.corners()), Some(rect),);
+        assert_eq!(Rect2D::<i64>::from_points([]), None);
+        assert_eq!(Bounds2D::new(3, 2).rect().map(|r| r.area()), Some(6));
+        assert_eq!(Bounds2D::new(0, 2).rect(), None);
+    }
+
+    #[test]
+    fn test_polygon() {
+        let square = Polygon2D::new(
+            Rect2D::new(Coord2D::new(0, 0), Coord2D::new(4, 4))
+                .corners()
+                .to_vec(),
+        );
+        assert_eq!(square.doubled_signed_area(), 32);
+        assert_eq!(square.perimeter(), 16.0);
+        assert_eq!(square.boundary_points(), 16);
+        assert_eq!(square.interior_points(), 9);
+        assert_eq!(square.enclosed_points(), 25);
+
+        let triangle = Polygon2D::new(vec![
+            Coord2D::new(0, 3),
+            Coord2D::new(4, 0),
+            Coord2D::new(0, 0),
+        ]);
+        assert_eq!(triangle.doubled_signed_area(), -12);
+        assert_eq!(triangle.area(), 6.0);
+        assert_eq!(triangle.perimeter(), 12.0);
+        assert_eq!(triangle.boundary_points(), 8);
+        assert_eq!(triangle.interior_points(), 3);
+        assert_eq!(
+            triangle.bounding_box(),
+            Some(Rect2D::new(Coord2D::new(0, 0), Coord2D::new(4, 3)))
+        );
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let bounds = Bounds2D::new(4, 3);
+        let coord = WrappingCoord2D::new(Coord2D::new(0, 2), bounds);
+        assert_eq!(
+            coord.go_in(&Direction2D::West).unbounded(),
+            Coord2D::new(3, 2)
+        );
+        assert_eq!(
+            coord.go_in(&Direction2D::South).unbounded(),
+            Coord2D::new(0, 0)
+        );
+        assert_eq!(
+            (coord + CoordDiff2D { dx: 9, dy: -7 }).unbounded(),
+            Coord2D::new(1, 1)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn prop_sub_roundtrips_with_add(a in coord(), b in coord()) {
+            prop_assert_eq!(b + (a - b), Some(a));
+            prop_assert_eq!(a + (a - a), Some(a));
+        }
+
+        #[test]
+        fn prop_add_below_zero(a in coord(), dx in i32::MIN..0) {
+            prop_assume!(a.x < dx.unsigned_abs());
+            prop_assert_eq!(a + CoordDiff2D { dx, dy: 0 }, None);
+        }
+
+        #[test]
+        fn prop_turn_right_has_period_four(dir in direction()) {
+            let turns = successors(Some(dir), |d| Some(d.turn_right()))
+                .take(5)
+                .collect::<Vec<_>>();
+            prop_assert_eq!(turns[4], dir);
+            prop_assert_eq!(turns[..4].iter().collect::<HashSet<_>>().len(), 4);
+        }
+
+        #[test]
+        fn prop_wrapping_add_inverts(x in 0..50u32, y in 0..50u32, dx in -1000..1000, dy in -1000..1000) {
+            let coord = WrappingCoord2D::new(Coord2D::new(x, y), Bounds2D::new(50, 50));
+            let diff = CoordDiff2D { dx, dy };
+            prop_assert_eq!(coord + diff + diff * -1, coord);
+        }
+
+        #[test]
+        fn prop_direction8_matches_direction2d(dir in direction(), turns in -10..10) {
+            prop_assert_eq!(
+                Direction8::from(dir.rotate_by(turns)),
+                Direction8::from(dir).rotate_by(turns * 2)
+            );
+            prop_assert_eq!(dir.turn_left().turn_right(), dir);
+        }
+
+        #[test]
+        fn prop_rotation_matches_directions(dir in direction()) {
+            prop_assert_eq!(dir.to_offset().rotate_cw(), dir.turn_right().to_offset());
+            prop_assert_eq!(dir.to_offset().rotate_ccw(), dir.turn_left().to_offset());
+        }
+
+        #[test]
+        fn prop_reduced_divides(dx in -1000..1000, dy in -1000..1000, n in 1..50) {
+            let diff = CoordDiff2D { dx, dy }.reduced();
+            prop_assert_eq!((diff * n).reduced(), diff);
+        }
+
+        #[test]
+        fn prop_cast_roundtrip(x in any::<u32>(), y in any::<u32>()) {
+            let coord = Coord2D::new(x, y);
+            prop_assert_eq!(coord.cast::<i64>().try_cast(), Some(coord));
+            prop_assert_eq!(
+                coord.cast::<u64>().manhattan_distance(&Coord2D::new(0, 0)),
+                coord.manhattan_distance(&Coord2D::new(0, 0))
+            );
+        }
+
+        #[test]
+        fn prop_rasterize_steps_to_neighbors(x1 in 0..100u32, y1 in 0..100u32, x2 in 0..100u32, y2 in 0..100u32) {
+            let (start, end) = (Coord2D::new(x1, y1), Coord2D::new(x2, y2));
+            let points: Vec<_> = Segment2D::new(start, end).rasterize().collect();
+            prop_assert_eq!(points.len() as u64, start.chebyshev_distance(&end) + 1);
+            prop_assert_eq!(points.last(), Some(&end));
+            for (a, b) in points.iter().zip(&points[1..]) {
+                prop_assert_eq!(a.chebyshev_distance(b), 1);
+            }
+        }
+
+        #[test]
+        fn prop_turn_right_rotates_offset(dir in direction()) {
+            let CoordDiff2D { dx, dy } = dir.to_offset();
+            prop_assert_eq!(dir.turn_right().to_offset(), CoordDiff2D { dx: -dy, dy: dx });
+        }
+
+        #[test]
+        fn prop_positions_are_valid(width in 0..30u32, height in 0..30u32) {
+            let bounds = Bounds2D::new(width, height);
+            prop_assert_eq!(bounds.positions().count(), (width * height) as usize);
+            prop_assert!(bounds.positions().all(|p| bounds.is_valid(&p)));
+        }
     }
 }
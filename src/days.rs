@@ -0,0 +1,46 @@
+//! The solutions of all days. Each day can be used through its
+//! [`Solution`](crate::solution::Solution) implementation, which parses the
+//! input and solves both parts, or through the functions of its module.
+
+use crate::solution::Runner;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+pub use day1::Day1;
+pub use day10::Day10;
+pub use day11::Day11;
+pub use day12::Day12;
+pub use day2::Day2;
+pub use day3::Day3;
+pub use day4::Day4;
+pub use day5::Day5;
+pub use day6::Day6;
+pub use day7::Day7;
+pub use day8::Day8;
+pub use day9::Day9;
+
+const SOLUTIONS: [&(dyn Runner + Sync); 12] = [
+    &Day1, &Day2, &Day3, &Day4, &Day5, &Day6, &Day7, &Day8, &Day9, &Day10, &Day11, &Day12,
+];
+
+/// Returns the solution for `day`, if there is one.
+pub fn get(day: u32) -> Option<&'static (dyn Runner + Sync)> {
+    let idx = usize::try_from(day.checked_sub(1)?).ok()?;
+    SOLUTIONS.get(idx).copied()
+}
+
+/// Iterates over all days that have a solution, in order.
+pub fn all() -> impl Iterator<Item = (u32, &'static (dyn Runner + Sync))> {
+    (1..).zip(SOLUTIONS)
+}
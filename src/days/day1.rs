@@ -0,0 +1,102 @@
+//! Day 1: Historian Hysteria
+
+use crate::{
+    error::{non_empty, Error, Result},
+    solution::Solution,
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = (Vec<u32>, Vec<u32>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLE: &'static str =
+        concat!("3   4\n", "4   3\n", "2   5\n", "1   3\n", "3   9\n", "3   3\n",);
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut list1 = vec![];
+        let mut list2 = vec![];
+
+        for line in non_empty(input)?.lines() {
+            let (a, b) = line
+                .split_once("   ")
+                .ok_or_else(|| Error::parse_at(input, line, "expected two location IDs"))?;
+            list1.push(a.parse().map_err(|e| Error::parse_at(input, a, e))?);
+            list2.push(b.parse().map_err(|e| Error::parse_at(input, b, e))?);
+        }
+
+        Ok((list1, list2))
+    }
+
+    fn part1((list1, list2): &Self::Input) -> u32 {
+        total_distance(list1.clone(), list2.clone()).unwrap()
+    }
+
+    fn part2((list1, list2): &Self::Input) -> u32 {
+        similarity_score(list1.clone(), list2.clone())
+    }
+}
+
+/// Pairs up the IDs of both lists in sorted order and sums their distances.
+/// Returns `None` if the lists differ in length.
+pub fn total_distance(mut list1: Vec<u32>, mut list2: Vec<u32>) -> Option<u32> {
+    list1.sort();
+    list2.sort();
+
+    if list1.len() != list2.len() {
+        return None;
+    }
+
+    Some(
+        list1
+            .iter()
+            .zip(list2.iter())
+            .map(|t| t.0.abs_diff(*t.1))
+            .sum(),
+    )
+}
+
+/// Sums each ID of `list1` multiplied by how often it appears in `list2`.
+pub fn similarity_score(list1: Vec<u32>, list2: Vec<u32>) -> u32 {
+    list1
+        .iter()
+        .map(|e| e * list2.iter().filter(|e2| *e2 == e).count() as u32)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(
+            total_distance(vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]),
+            Some(11)
+        );
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(
+            similarity_score(vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3]),
+            31
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            Day1::parse(Day1::EXAMPLE).unwrap(),
+            (vec![3, 4, 2, 1, 3, 3], vec![4, 3, 5, 3, 9, 3])
+        );
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(matches!(Day1::parse(""), Err(Error::InvalidInput(_))));
+        assert!(matches!(Day1::parse("\n"), Err(Error::InvalidInput(_))));
+    }
+}
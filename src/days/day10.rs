@@ -0,0 +1,136 @@
+//! Day 10: Hoof It
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    containers::{parse_grid, Vec2D, Vec2DIndex},
+    error::Result,
+    solution::Solution,
+};
+
+pub type Height = u8;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec2D<Height>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLE: &'static str = concat!(
+        "89010123\n",
+        "78121874\n",
+        "87430965\n",
+        "96549874\n",
+        "45678903\n",
+        "32019012\n",
+        "01329801\n",
+        "10456732\n",
+    );
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        sum_trailhead_scores(map)
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        sum_trailhead_ratings(map)
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec2D<Height>> {
+    parse_grid(input, |c| {
+        c.to_digit(10)
+            .map(|d| d as Height)
+            .ok_or("expected a height")
+    })
+}
+
+/// Sums the number of peaks reachable from each trailhead.
+pub fn sum_trailhead_scores(map: &Vec2D<Height>) -> u64 {
+    sum_trailhead_evals(
+        map,
+        HashSet::new,
+        |mut s, (p, _)| {
+            s.insert(p);
+            s
+        },
+        |s| s.len() as u64,
+    )
+}
+
+/// Sums the number of distinct trails starting at each trailhead.
+pub fn sum_trailhead_ratings(map: &Vec2D<Height>) -> u64 {
+    sum_trailhead_evals(map, || 0, |r, _| r + 1, |r| r)
+}
+
+fn sum_trailhead_evals<I, F, R, G>(map: &Vec2D<Height>, init: I, fold: F, finalize: G) -> u64
+where
+    I: Fn() -> R,
+    F: Fn(R, (Vec2DIndex, Height)) -> R,
+    G: Fn(R) -> u64,
+{
+    map.enumerated_iter()
+        .filter(|(_, &height)| height == 0)
+        .map(|(p, h)| eval_trailhead(map, (p, *h), &init, &fold, &finalize))
+        .sum()
+}
+
+fn eval_trailhead<I, F, G, R>(
+    map: &Vec2D<Height>,
+    trailhead: (Vec2DIndex, Height),
+    init: I,
+    fold: F,
+    finalize: G,
+) -> u64
+where
+    I: Fn() -> R,
+    F: Fn(R, (Vec2DIndex, Height)) -> R,
+    G: Fn(R) -> u64,
+{
+    let mut worklist = VecDeque::new();
+    let mut acc = init();
+
+    worklist.push_back(trailhead);
+
+    while let Some((pos, cur)) = worklist.pop_front() {
+        if cur == 9 {
+            acc = fold(acc, (pos, cur));
+            continue;
+        }
+
+        map.neighbors4(&pos)
+            .filter(|(_, _, new)| **new == cur + 1)
+            .for_each(|(_, i, new)| worklist.push_back((i, *new)));
+    }
+
+    finalize(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::solution::Solution;
+
+    use super::{parse_input, sum_trailhead_ratings, sum_trailhead_scores, Day10};
+
+    #[test]
+    fn test_part_1_1() {
+        let input = concat!("0123\n", "1234\n", "8765\n", "9876\n",);
+        assert_eq!(sum_trailhead_scores(&parse_input(input).unwrap()), 1);
+    }
+
+    #[test]
+    fn test_part_1_2() {
+        let input = Day10::EXAMPLE;
+        assert_eq!(sum_trailhead_scores(&parse_input(input).unwrap()), 36);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = Day10::EXAMPLE;
+        assert_eq!(sum_trailhead_ratings(&parse_input(input).unwrap()), 81);
+    }
+}
@@ -1,20 +1,39 @@
+//! Day 11: Plutonian Pebbles
+
 use std::collections::HashMap;
 
-use aoc_2024::{
-    read_input,
+use nom::{character::complete, multi::separated_list1, IResult};
+
+use crate::{
+    error::{parse_all, Result},
+    solution::Solution,
     util::{count_digits, split_digits},
 };
-use nom::{character::complete, multi::separated_list1, IResult};
 
-fn main() {
-    let input = read_input("day11");
-    let init = parse_input(&input).unwrap().1;
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLE: &'static str = "125 17";
 
-    println!("Part 1: {}", count_after_blinks(&init, 25));
-    println!("Part 1: {}", count_after_blinks(&init, 75));
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(init: &Self::Input) -> usize {
+        count_after_blinks(init, 25)
+    }
+
+    fn part2(init: &Self::Input) -> usize {
+        count_after_blinks(init, 75)
+    }
 }
 
-fn count_after_blinks(initial_arrangement: &[u64], steps: usize) -> usize {
+/// Counts the stones after blinking `steps` times.
+pub fn count_after_blinks(initial_arrangement: &[u64], steps: usize) -> usize {
     let mut lookup = HashMap::<(u64, usize), usize>::new();
     initial_arrangement
         .iter()
@@ -22,7 +41,9 @@ fn count_after_blinks(initial_arrangement: &[u64], steps: usize) -> usize {
         .sum()
 }
 
-fn count_stone(stone: u64, steps: usize, lookup: &mut HashMap<(u64, usize), usize>) -> usize {
+/// Counts the stones a single stone turns into after `steps` blinks,
+/// memoizing in `lookup`.
+pub fn count_stone(stone: u64, steps: usize, lookup: &mut HashMap<(u64, usize), usize>) -> usize {
     if let Some(res) = lookup.get(&(stone, steps)) {
         *res
     } else {
@@ -59,7 +80,9 @@ fn parse_input(input: &str) -> IResult<&str, Vec<u64>> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{count_after_blinks, parse_input};
+    use crate::solution::Solution;
+
+    use super::{count_after_blinks, parse_input, Day11};
 
     #[test]
     fn test_part_1_1() {
@@ -70,14 +93,14 @@ mod tests {
 
     #[test]
     fn test_part_1_2() {
-        let input = "125 17";
+        let input = Day11::EXAMPLE;
         let init = parse_input(input).unwrap().1;
         assert_eq!(count_after_blinks(&init, 6), 22);
     }
 
     #[test]
     fn test_part_1_3() {
-        let input = "125 17";
+        let input = Day11::EXAMPLE;
         let init = parse_input(input).unwrap().1;
         assert_eq!(count_after_blinks(&init, 25), 55312);
     }
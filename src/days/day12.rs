@@ -0,0 +1,276 @@
+//! Day 12: Garden Groups
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    containers::Vec2D,
+    coord::{Coord2D, Direction2D},
+    error::Result,
+    grid::BitGrid,
+    solution::Solution,
+};
+
+struct Region {
+    area: BitGrid,
+    border: HashSet<(Direction2D, Coord2D)>,
+}
+
+struct Regions<'a, E> {
+    map: &'a Vec2D<E>,
+    unchecked_positions: BitGrid,
+}
+
+/// The price of fencing all regions.
+pub struct FencingPrice {
+    /// Area times perimeter.
+    pub total: u64,
+    /// Area times number of sides.
+    pub discounted: u64,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec2D<char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLE: &'static str = concat!(
+        "RRRRIICCFF\n",
+        "RRRRIICCCF\n",
+        "VVRRRCCFFF\n",
+        "VVRCCCJFFF\n",
+        "VVVVCJJCFE\n",
+        "VVIVCCJJEE\n",
+        "VVIIICJJEE\n",
+        "MIIIIIJJEE\n",
+        "MIIISIJEEE\n",
+        "MMMISSJEEE\n",
+    );
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(input.parse()?)
+    }
+
+    fn part1(map: &Self::Input) -> u64 {
+        total_fencing_price(map).total
+    }
+
+    fn part2(map: &Self::Input) -> u64 {
+        total_fencing_price(map).discounted
+    }
+}
+
+/// Computes the fencing price of all regions of the map.
+pub fn total_fencing_price(map: &Vec2D<char>) -> FencingPrice {
+    Regions::new(map).fold(
+        FencingPrice {
+            total: 0,
+            discounted: 0,
+        },
+        |mut acc, r| {
+            acc.total += r.area.len() as u64 * r.border.len() as u64;
+            acc.discounted += r.area.len() as u64 * count_sides(&r);
+            acc
+        },
+    )
+}
+
+fn count_sides(region: &Region) -> u64 {
+    let mut all_considered_sides: HashSet<(Direction2D, Coord2D)> = HashSet::new();
+
+    let valid_neighbors = |(dir, pos): (Direction2D, Coord2D)| {
+        Direction2D::all()
+            .filter_map(move |d| pos.go_in(&d))
+            .map(move |i| (dir, i))
+            .filter(|e| region.border.contains(e))
+    };
+
+    for init in region.border.iter() {
+        let mut worklist = VecDeque::new();
+        let mut reachable_edges: HashSet<(Direction2D, Coord2D)> = HashSet::new();
+        worklist.push_back(*init);
+
+        while let Some(edge) = worklist.pop_front() {
+            for n in valid_neighbors(edge) {
+                if !reachable_edges.contains(&n) {
+                    reachable_edges.insert(n);
+                    worklist.push_back(n);
+                }
+            }
+        }
+
+        if !reachable_edges
+            .iter()
+            .any(|e| all_considered_sides.contains(e))
+        {
+            all_considered_sides.insert(*init);
+        }
+    }
+
+    all_considered_sides.len() as u64
+}
+
+impl<'a, E: PartialEq + Copy> Regions<'a, E> {
+    fn new(map: &'a Vec2D<E>) -> Self {
+        let bounds = map.bounds();
+        Self {
+            map,
+            unchecked_positions: BitGrid::from_coords(bounds, bounds.positions()),
+        }
+    }
+
+    fn flood_fill_region(&self, start: Coord2D) -> Region {
+        let plant = self.map[start];
+        let mut area = BitGrid::from_coords(self.map.bounds(), [start]);
+        let mut worklist = vec![start];
+        while let Some(pos) = worklist.pop() {
+            for next in Direction2D::all().filter_map(|dir| pos.go_in(&dir)) {
+                if self.map.get_index(&next) == Some(&plant) && area.insert(next) {
+                    worklist.push(next);
+                }
+            }
+        }
+
+        let border = area
+            .iter()
+            .flat_map(|pos| Direction2D::all().map(move |dir| (dir, pos)))
+            .filter(|(dir, pos)| !pos.go_in(dir).is_some_and(|next| area.contains(&next)))
+            .collect();
+
+        Region { area, border }
+    }
+}
+
+impl<E: PartialEq + Copy> Iterator for Regions<'_, E> {
+    type Item = Region;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = self.unchecked_positions.iter().next()?;
+        let region = self.flood_fill_region(start);
+        self.unchecked_positions.difference_with(&region.area);
+        Some(region)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::{
+        coord::{Coord2D, Direction2D, Polygon2D},
+        solution::Solution,
+    };
+
+    use super::{total_fencing_price, Day12, Region, Regions};
+
+    /// Traces the fences of `region` as polygons through the corners of its
+    /// cells, clockwise around the outside and counterclockwise around holes.
+    fn outlines(region: &Region) -> Vec<Polygon2D> {
+        let mut edges: HashMap<Coord2D<i64>, Vec<Coord2D<i64>>> = HashMap::new();
+        for (dir, pos) in &region.border {
+            let (x, y) = (pos.x() as i64, pos.y() as i64);
+            let (from, to) = match dir {
+                Direction2D::North => ((x, y), (x + 1, y)),
+                Direction2D::East => ((x + 1, y), (x + 1, y + 1)),
+                Direction2D::South => ((x + 1, y + 1), (x, y + 1)),
+                Direction2D::West => ((x, y + 1), (x, y)),
+            };
+            edges
+                .entry(Coord2D::new(from.0, from.1))
+                .or_default()
+                .push(Coord2D::new(to.0, to.1));
+        }
+
+        let mut outlines = vec![];
+        while let Some(&start) = edges
+            .iter()
+            .find(|(_, to)| !to.is_empty())
+            .map(|(from, _)| from)
+        {
+            let mut vertices = vec![];
+            let mut current = start;
+            loop {
+                vertices.push(current);
+                current = edges.get_mut(&current).unwrap().pop().unwrap();
+                if current == start {
+                    break;
+                }
+            }
+            outlines.push(Polygon2D::new(vertices));
+        }
+        outlines
+    }
+
+    #[test]
+    fn test_outlines_match_regions() {
+        let with_holes = concat!("OOOOO\n", "OXOXO\n", "OOOOO\n", "OXOXO\n", "OOOOO\n");
+        for input in [Day12::EXAMPLE, with_holes] {
+            let map = Day12::parse(input).unwrap();
+            for region in Regions::new(&map) {
+                let outlines = outlines(&region);
+                let area: i128 = outlines.iter().map(|o| o.doubled_signed_area()).sum();
+                let perimeter: u64 = outlines.iter().map(|o| o.boundary_points()).sum();
+                assert_eq!(area, 2 * region.area.len() as i128);
+                assert_eq!(perimeter, region.border.len() as u64);
+            }
+        }
+    }
+
+    fn run_part_1(input: &str, exp: u64) {
+        let map = Day12::parse(input).unwrap();
+        assert_eq!(total_fencing_price(&map).total, exp);
+    }
+
+    fn run_part_2(input: &str, exp: u64) {
+        let map = Day12::parse(input).unwrap();
+        assert_eq!(total_fencing_price(&map).discounted, exp);
+    }
+
+    #[test]
+    fn test_part_1_1() {
+        run_part_1(concat!("AAAA\n", "BBCD\n", "BBCC\n", "EEEC\n"), 140);
+    }
+
+    #[test]
+    fn test_part_1_2() {
+        run_part_1(
+            concat!("OOOOO\n", "OXOXO\n", "OOOOO\n", "OXOXO\n", "OOOOO\n"),
+            772,
+        );
+    }
+
+    #[test]
+    fn test_part_1_3() {
+        run_part_1(Day12::EXAMPLE, 1930);
+    }
+
+    #[test]
+    fn test_part_2_1() {
+        run_part_2(concat!("AAAA\n", "BBCD\n", "BBCC\n", "EEEC\n"), 80);
+    }
+
+    #[test]
+    fn test_part_2_2() {
+        run_part_2(
+            concat!("OOOOO\n", "OXOXO\n", "OOOOO\n", "OXOXO\n", "OOOOO\n"),
+            436,
+        );
+    }
+
+    #[test]
+    fn test_part_2_3() {
+        run_part_2(
+            concat!("EEEEE\n", "EXXXX\n", "EEEEE\n", "EXXXX\n", "EEEEE\n",),
+            236,
+        );
+    }
+
+    #[test]
+    fn test_part_2_4() {
+        run_part_2(
+            concat!("AAAAAA\n", "AAABBA\n", "AAABBA\n", "ABBAAA\n", "ABBAAA\n", "AAAAAA\n",),
+            368,
+        );
+    }
+}
@@ -1,39 +1,68 @@
-use aoc_2024::iters::IteratorExtensions;
-use aoc_2024::read_input;
+//! Day 2: Red-Nosed Reports
+
+use std::num::ParseIntError;
+
+use crate::error::{self, non_empty, Error};
+use crate::iters::IteratorExtensions;
+use crate::solution::Solution;
 
 trait SafeDiffs: Iterator {
     fn safe_diffs(self) -> bool;
 }
 
 #[derive(Debug)]
-struct Report {
+pub struct Report {
     levels: Vec<i32>,
 }
 
-fn main() {
-    let input = read_input("day2");
-    let reports: Vec<_> = input.lines().map(Report::from_input).collect();
+pub struct Day2;
 
-    println!("Part 1: {}", reports.iter().filter(|r| r.safe()).count());
-    println!(
-        "Part 2: {}",
-        reports.iter().filter(|r| r.actually_safe()).count()
+impl Solution for Day2 {
+    type Input = Vec<Report>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLE: &'static str = concat!(
+        "7 6 4 2 1\n",
+        "1 2 7 8 9\n",
+        "9 7 6 2 1\n",
+        "1 3 2 4 5\n",
+        "8 6 4 4 1\n",
+        "1 3 6 7 9\n",
     );
+
+    fn parse(input: &str) -> error::Result<Self::Input> {
+        non_empty(input)?
+            .lines()
+            .map(|l| Report::from_input(l).map_err(|e| Error::parse_at(input, l, e)))
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input) -> usize {
+        reports.iter().filter(|r| r.safe()).count()
+    }
+
+    fn part2(reports: &Self::Input) -> usize {
+        reports.iter().filter(|r| r.actually_safe()).count()
+    }
 }
 
 impl Report {
-    pub fn from_input(input: &str) -> Self {
+    /// Parses one line of space separated levels.
+    pub fn from_input(input: &str) -> Result<Self, ParseIntError> {
         let levels = input
             .split_whitespace()
-            .map(|s| s.parse().unwrap())
-            .collect();
-        Self { levels }
+            .map(|s| s.parse())
+            .collect::<Result<_, _>>()?;
+        Ok(Self { levels })
     }
 
+    /// Whether the levels change monotonically by 1 to 3 at each step.
     pub fn safe(&self) -> bool {
         self.levels.clone().into_iter().differences().safe_diffs()
     }
 
+    /// Whether the report is safe after removing at most one level.
     pub fn actually_safe(&self) -> bool {
         (0..self.levels.len())
             .filter(|&i| {
@@ -59,24 +88,15 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::*;
-
-    const INPUT: &str = concat!(
-        "7 6 4 2 1\n",
-        "1 2 7 8 9\n",
-        "9 7 6 2 1\n",
-        "1 3 2 4 5\n",
-        "8 6 4 4 1\n",
-        "1 3 6 7 9\n",
-    );
+    use super::*;
 
     #[test]
     fn test_part_1() {
         assert_eq!(
             2,
-            INPUT
+            Day2::EXAMPLE
                 .lines()
-                .map(Report::from_input)
+                .map(|l| Report::from_input(l).unwrap())
                 .filter(|r| r.safe())
                 .inspect(|r| println!("safe: {:?}", r))
                 .count()
@@ -87,11 +107,16 @@ mod tests {
     fn test_part_2() {
         assert_eq!(
             4,
-            INPUT
+            Day2::EXAMPLE
                 .lines()
-                .map(Report::from_input)
+                .map(|l| Report::from_input(l).unwrap())
                 .filter(|r| r.actually_safe())
                 .count()
         );
     }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(matches!(Day2::parse(""), Err(Error::InvalidInput(_))));
+    }
 }
@@ -0,0 +1,88 @@
+//! Day 3: Mull It Over
+
+use regex::Regex;
+
+use crate::{
+    error::{non_empty, Result},
+    solution::Solution,
+};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLE: &'static str =
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))mul(,4)mul(a,b)";
+    const EXAMPLE_PART2: &'static str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(non_empty(input)?.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        execute_without_conditions(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        execute_with_conditions(input)
+    }
+}
+
+/// Sums the products of all valid `mul` instructions.
+pub fn execute_without_conditions<T: AsRef<str>>(input: T) -> u32 {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+    re.captures_iter(input.as_ref())
+        .map(|c| c.extract())
+        .map(|(_, [n1, n2])| n1.parse::<u32>().unwrap() * n2.parse::<u32>().unwrap())
+        .sum()
+}
+
+/// Sums the products of all `mul` instructions enabled by `do()` and
+/// `don't()`.
+pub fn execute_with_conditions<T: AsRef<str>>(input: T) -> u32 {
+    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\)").unwrap();
+    let mut active = true;
+    let mut result = 0;
+    for c in re.captures_iter(input.as_ref()) {
+        let total_match = c.get(0).unwrap();
+        match total_match.as_str() {
+            "do()" => active = true,
+            "don't()" => active = false,
+            _ => {
+                let n1 = c.get(1).unwrap().as_str().parse::<u32>().unwrap();
+                let n2 = c.get(2).unwrap().as_str().parse::<u32>().unwrap();
+                if active {
+                    result += n1 * n2;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Error;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(161, execute_without_conditions(Day3::EXAMPLE));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(48, execute_with_conditions(Day3::EXAMPLE_PART2));
+    }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(matches!(Day3::parse(""), Err(Error::InvalidInput(_))));
+    }
+}
@@ -0,0 +1,115 @@
+//! Day 4: Ceres Search
+
+use crate::containers::{Vec2D, Vec2DIndex};
+use crate::coord::Direction8;
+use crate::error::{non_empty, Error, Result};
+use crate::solution::Solution;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec2D<char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLE: &'static str = concat!(
+        "MMMSXXMASM\n",
+        "MSAMXMSMSA\n",
+        "AMXSXMAAMM\n",
+        "MSAMASMSMX\n",
+        "XMASAMXAMM\n",
+        "XXAMMXXAMA\n",
+        "SMSMSASXSS\n",
+        "SAXAMASAAA\n",
+        "MAMMMXMMMM\n",
+        "MXMXAXMASX\n",
+    );
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let input = non_empty(input)?;
+        if let Some(i) = input.find(|c| !matches!(c, 'X' | 'M' | 'A' | 'S' | '\n' | '\r')) {
+            return Err(Error::parse_at(
+                input,
+                &input[i..],
+                "expected one of X, M, A or S",
+            ));
+        }
+        Ok(input.parse()?)
+    }
+
+    fn part1(input: &Self::Input) -> u32 {
+        count_xmas(input)
+    }
+
+    fn part2(input: &Self::Input) -> u32 {
+        count_cross_mas(input)
+    }
+}
+
+/// Counts the occurrences of `XMAS` in all eight directions.
+pub fn count_xmas(input: &Vec2D<char>) -> u32 {
+    input
+        .rows()
+        .map(collect_line)
+        .chain(input.columns().map(collect_line))
+        .chain(input.diagonals().map(collect_line))
+        .chain(input.anti_diagonals().map(collect_line))
+        .map(|line| line.matches("XMAS").count() + line.matches("SAMX").count())
+        .sum::<usize>() as u32
+}
+
+/// Counts the two `MAS` crossing in the shape of an X.
+pub fn count_cross_mas(input: &Vec2D<char>) -> u32 {
+    input
+        .indizes()
+        .filter(|i| is_cross_mas_middle(input, i))
+        .count() as u32
+}
+
+fn collect_line<'a>(line: impl Iterator<Item = (Vec2DIndex, &'a char)>) -> String {
+    line.map(|(_, c)| c).collect()
+}
+
+fn is_cross_mas_middle(input: &Vec2D<char>, index: &Vec2DIndex) -> bool {
+    let is_mas = |start: Direction8| {
+        let ends = input
+            .neighbors_with(index, [start, start.turn_around()])
+            .map(|(_, _, c)| c)
+            .collect::<String>();
+        ends == "MS" || ends == "SM"
+    };
+
+    input[*index] == 'A' && is_mas(Direction8::NorthWest) && is_mas(Direction8::NorthEast)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::containers::Vec2D;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = Vec2D::from_lines(Day4::EXAMPLE.lines().map(|l| l.chars()));
+        assert_eq!(count_xmas(&input), 18);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = Vec2D::from_lines(Day4::EXAMPLE.lines().map(|l| l.chars()));
+        assert_eq!(count_cross_mas(&input), 9);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(matches!(Day4::parse(""), Err(Error::InvalidInput(_))));
+        assert!(matches!(
+            Day4::parse("XMAS\nSAxX\n"),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+}
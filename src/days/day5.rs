@@ -1,30 +1,92 @@
+//! Day 5: Print Queue
+
 use std::{
     cmp::Ordering,
     collections::{HashMap, HashSet},
 };
 
-use aoc_2024::{iters::IteratorExtensions, read_input};
 use nom::{
-    character::{complete, streaming},
+    character::complete,
     multi::{fold_many1, separated_list1},
     sequence::{separated_pair, terminated},
     IResult,
 };
 
-type Update = Vec<u32>;
+use crate::{
+    error::{line_end, parse_all, Error, Result},
+    iters::IteratorExtensions,
+    solution::Solution,
+};
+
+pub type Update = Vec<u32>;
 
-struct PageOrderingRules {
+/// For each page, the pages that must come after it.
+#[derive(Default)]
+pub struct PageOrderingRules {
     rules: HashMap<u32, HashSet<u32>>,
 }
 
-fn main() {
-    let input = read_input("day5");
-    let (rules, updates) = parse_input(&input).unwrap().1;
-    println!("Part 1: {}", add_up_correctly_odered(&updates, &rules));
-    println!("Part 2: {}", add_up_corrected(&updates, &rules));
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (PageOrderingRules, Vec<Update>);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    const EXAMPLE: &'static str = concat!(
+        "47|53\n",
+        "97|13\n",
+        "97|61\n",
+        "97|47\n",
+        "75|29\n",
+        "61|13\n",
+        "75|53\n",
+        "29|13\n",
+        "97|29\n",
+        "53|29\n",
+        "61|53\n",
+        "97|53\n",
+        "61|29\n",
+        "47|13\n",
+        "75|47\n",
+        "97|75\n",
+        "47|61\n",
+        "75|61\n",
+        "47|29\n",
+        "75|13\n",
+        "53|13\n",
+        "\n",
+        "75,47,61,53,29\n",
+        "97,61,53,29,13\n",
+        "75,29,13\n",
+        "75,97,47,61,53\n",
+        "61,13,29\n",
+        "97,13,75,29,47\n",
+    );
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let (rules, updates) = parse_all(input, parse_input)?;
+
+        if let Some(update) = updates.iter().find(|u| u.len() % 2 == 0) {
+            return Err(Error::invalid_input(format!(
+                "update {update:?} has no middle page"
+            )));
+        }
+
+        Ok((rules, updates))
+    }
+
+    fn part1((rules, updates): &Self::Input) -> u32 {
+        add_up_correctly_odered(updates, rules)
+    }
+
+    fn part2((rules, updates): &Self::Input) -> u32 {
+        add_up_corrected(updates, rules)
+    }
 }
 
-fn add_up_correctly_odered(updates: &[Update], rules: &PageOrderingRules) -> u32 {
+/// Sums the middle pages of the updates that are already ordered.
+pub fn add_up_correctly_odered(updates: &[Update], rules: &PageOrderingRules) -> u32 {
     updates
         .iter()
         .filter(|u| ordered_by_rules(u, rules))
@@ -32,7 +94,8 @@ fn add_up_correctly_odered(updates: &[Update], rules: &PageOrderingRules) -> u32
         .sum()
 }
 
-fn add_up_corrected(updates: &[Update], rules: &PageOrderingRules) -> u32 {
+/// Sums the middle pages of the unordered updates after ordering them.
+pub fn add_up_corrected(updates: &[Update], rules: &PageOrderingRules) -> u32 {
     updates
         .iter()
         .filter(|u| !ordered_by_rules(u, rules))
@@ -41,14 +104,16 @@ fn add_up_corrected(updates: &[Update], rules: &PageOrderingRules) -> u32 {
         .sum()
 }
 
-fn ordered_by_rules(update: &Update, rules: &PageOrderingRules) -> bool {
+/// Whether `update` satisfies all applicable rules.
+pub fn ordered_by_rules(update: &Update, rules: &PageOrderingRules) -> bool {
     update
         .iter()
         .enumerate()
         .is_sorted_by(|a, b| compare_with_rules(a, b, rules) == Ordering::Less)
 }
 
-fn correct_with_rules(update: &Update, rules: &PageOrderingRules) -> Update {
+/// Returns `update` ordered according to `rules`.
+pub fn correct_with_rules(update: &Update, rules: &PageOrderingRules) -> Update {
     let mut update = update.iter().enumerate().collect::<Vec<_>>();
 
     update.sort_by(|a, b| compare_with_rules(a, b, rules));
@@ -78,8 +143,8 @@ fn compare_with_rules(a: &(usize, &u32), b: &(usize, &u32), rules: &PageOrdering
 fn parse_updates(input: &str) -> IResult<&str, Vec<Update>> {
     fold_many1(
         terminated(
-            separated_list1(streaming::char(','), complete::u32),
-            complete::newline,
+            separated_list1(complete::char(','), complete::u32),
+            line_end,
         ),
         Vec::new,
         |mut acc, pages| {
@@ -92,7 +157,7 @@ fn parse_updates(input: &str) -> IResult<&str, Vec<Update>> {
 fn parse_page_ordering_rules(input: &str) -> IResult<&str, PageOrderingRules> {
     fold_many1(
         terminated(
-            separated_pair(complete::u32, streaming::char('|'), complete::u32),
+            separated_pair(complete::u32, complete::char('|'), complete::u32),
             complete::newline,
         ),
         PageOrderingRules::new,
@@ -109,11 +174,10 @@ fn parse_input(input: &str) -> IResult<&str, (PageOrderingRules, Vec<Update>)> {
 
 impl PageOrderingRules {
     pub fn new() -> Self {
-        Self {
-            rules: HashMap::new(),
-        }
+        Self::default()
     }
 
+    /// Requires `first` to come before `second`.
     pub fn add_rule(&mut self, first: u32, second: u32) {
         self.rules
             .entry(first)
@@ -126,54 +190,31 @@ impl PageOrderingRules {
 
 #[cfg(test)]
 mod tests {
-    use crate::{add_up_corrected, add_up_correctly_odered, correct_with_rules, parse_input};
+    use crate::solution::Solution;
 
-    const INPUT: &str = concat!(
-        "47|53\n",
-        "97|13\n",
-        "97|61\n",
-        "97|47\n",
-        "75|29\n",
-        "61|13\n",
-        "75|53\n",
-        "29|13\n",
-        "97|29\n",
-        "53|29\n",
-        "61|53\n",
-        "97|53\n",
-        "61|29\n",
-        "47|13\n",
-        "75|47\n",
-        "97|75\n",
-        "47|61\n",
-        "75|61\n",
-        "47|29\n",
-        "75|13\n",
-        "53|13\n",
-        "\n",
-        "75,47,61,53,29\n",
-        "97,61,53,29,13\n",
-        "75,29,13\n",
-        "75,97,47,61,53\n",
-        "61,13,29\n",
-        "97,13,75,29,47\n",
-    );
+    use super::{add_up_corrected, add_up_correctly_odered, correct_with_rules, parse_input, Day5};
 
     #[test]
     fn test_part_1() {
-        let (rules, updates) = parse_input(INPUT).unwrap().1;
+        let (rules, updates) = parse_input(Day5::EXAMPLE).unwrap().1;
+        assert_eq!(add_up_correctly_odered(&updates, &rules), 143);
+    }
+
+    #[test]
+    fn test_without_final_newline() {
+        let (rules, updates) = Day5::parse(Day5::EXAMPLE.trim_end()).unwrap();
         assert_eq!(add_up_correctly_odered(&updates, &rules), 143);
     }
 
     #[test]
     fn test_part_2() {
-        let (rules, updates) = parse_input(INPUT).unwrap().1;
+        let (rules, updates) = parse_input(Day5::EXAMPLE).unwrap().1;
         assert_eq!(add_up_corrected(&updates, &rules), 123);
     }
 
     #[test]
     fn test_correct() {
-        let (rules, _) = parse_input(INPUT).unwrap().1;
+        let (rules, _) = parse_input(Day5::EXAMPLE).unwrap().1;
         let tests = vec![
             (vec![75, 97, 47, 61, 53], vec![97, 75, 47, 61, 53]),
             (vec![61, 13, 29], vec![61, 29, 13]),
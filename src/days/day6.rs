@@ -0,0 +1,181 @@
+//! Day 6: Guard Gallivant
+
+use std::collections::HashSet;
+
+use nom::{character::complete, multi::fold_many1, sequence::terminated};
+
+use crate::{
+    coord::{BoundedCoord2D, Bounds2D, Coord2D, Direction2D},
+    error::{line_end, parse_all, Error, Result},
+    grid::BitGrid,
+    solution::Solution,
+};
+
+pub type Obstacles = BitGrid;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = (Obstacles, BoundedCoord2D);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLE: &'static str = "....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1((obstacles, init): &Self::Input) -> usize {
+        travel(init, obstacles).len()
+    }
+
+    fn part2((obstacles, init): &Self::Input) -> usize {
+        count_obstacles_causing_loop(init, obstacles)
+    }
+}
+
+/// Counts the positions where a single new obstacle makes the guard loop.
+pub fn count_obstacles_causing_loop(init: &BoundedCoord2D, obstacles: &Obstacles) -> usize {
+    let mut newobstacles = obstacles.clone();
+    travel(init, obstacles)
+        .iter()
+        .filter(|c| **c != init.unbounded())
+        .filter(|c| !obstacles.contains(c))
+        .filter(|c| {
+            newobstacles.insert(**c);
+            let res = travel_loops(init, &newobstacles);
+            newobstacles.remove(c);
+            res
+        })
+        .count()
+}
+
+fn states_to_positions(states: &HashSet<(Coord2D, Direction2D)>) -> HashSet<Coord2D> {
+    states.iter().map(|(c, _)| *c).collect()
+}
+
+/// Returns the positions the guard visits before leaving the map.
+pub fn travel(init: &BoundedCoord2D, obstacles: &Obstacles) -> HashSet<Coord2D> {
+    let mut visited: HashSet<(Coord2D, Direction2D)> = HashSet::new();
+    let mut cur_pos = *init;
+    let mut cur_dir = Direction2D::North;
+
+    visited.insert((cur_pos.unbounded(), cur_dir));
+    while let Some(new_pos) = cur_pos.go_in(&cur_dir) {
+        if obstacles.contains(&new_pos.unbounded()) {
+            cur_dir = cur_dir.turn_right();
+        } else {
+            cur_pos = new_pos;
+            if !visited.insert((cur_pos.unbounded(), cur_dir)) {
+                panic!("travel() looped");
+            }
+        }
+    }
+
+    states_to_positions(&visited)
+}
+
+/// Whether the guard walks in a loop instead of leaving the map.
+pub fn travel_loops(init: &BoundedCoord2D, obstacles: &Obstacles) -> bool {
+    let mut cur_pos = *init;
+    let mut cur_dir = Direction2D::North;
+    let mut turns = Vec::new();
+
+    while let Some(new_pos) = cur_pos.go_in(&cur_dir) {
+        if obstacles.contains(&new_pos.unbounded()) {
+            cur_dir = cur_dir.turn_right();
+            if turns.contains(&(cur_pos, cur_dir)) {
+                return true;
+            }
+            turns.push((cur_pos, cur_dir));
+        } else {
+            cur_pos = new_pos;
+        }
+        if cur_pos == *init && cur_dir == Direction2D::North {
+            return true;
+        }
+    }
+    false
+}
+
+fn parse_input(input: &str) -> Result<(Obstacles, BoundedCoord2D)> {
+    let (obstacles, init, _, bounds) = parse_all(
+        input,
+        fold_many1(
+            terminated(nom::multi::many1(complete::one_of(".#^")), line_end),
+            || (HashSet::<Coord2D>::new(), vec![], 0, Bounds2D::new(0, 0)),
+            |mut acc, l| {
+                acc.3.expand_height(acc.2 + 1);
+                for (idx, ch) in l.iter().enumerate() {
+                    {
+                        acc.3.expand_width(idx as u32 + 1);
+                    }
+                    match ch {
+                        '^' => acc.1.push(Coord2D::new(idx as u32, acc.2)),
+                        '.' => {}
+                        '#' => {
+                            acc.0.insert(Coord2D::new(idx as u32, acc.2));
+                        }
+                        c => {
+                            panic!("unknown char {c}")
+                        }
+                    }
+                }
+                acc.2 += 1;
+                acc
+            },
+        ),
+    )?;
+
+    match init[..] {
+        [init] => Ok((
+            BitGrid::from_coords(bounds, obstacles),
+            init.into_bounded(bounds),
+        )),
+        [] => Err(Error::invalid_input("no initial position")),
+        _ => Err(Error::invalid_input("multiple initial positions")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{coord::Coord2D, solution::Solution};
+
+    use super::{count_obstacles_causing_loop, parse_input, travel, travel_loops, Day6};
+
+    #[test]
+    fn test_part_1() {
+        let (obstacles, init) = parse_input(Day6::EXAMPLE).unwrap();
+        assert_eq!(travel(&init, &obstacles).len(), 41);
+    }
+
+    #[test]
+    fn test_without_final_newline() {
+        let (obstacles, init) = parse_input(Day6::EXAMPLE.trim_end()).unwrap();
+        assert_eq!(travel(&init, &obstacles).len(), 41);
+    }
+
+    #[test]
+    fn test_loop() {
+        let (mut obstacles, init) = parse_input(Day6::EXAMPLE).unwrap();
+        obstacles.insert(Coord2D::new(3, 6));
+        assert!(travel_loops(&init, &obstacles));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let (obstacles, init) = parse_input(Day6::EXAMPLE).unwrap();
+        assert_eq!(count_obstacles_causing_loop(&init, &obstacles), 6);
+    }
+}
@@ -1,6 +1,7 @@
+//! Day 7: Bridge Repair
+
 use std::{collections::HashSet, iter::repeat_n};
 
-use aoc_2024::{read_input, util::concat_numbers};
 use itertools::Itertools;
 use nom::{
     bytes::complete::tag,
@@ -10,6 +11,12 @@ use nom::{
     IResult,
 };
 
+use crate::{
+    error::{line_end, parse_all, Result},
+    solution::Solution,
+    util::concat_numbers,
+};
+
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
 enum Operator {
     Add,
@@ -18,19 +25,46 @@ enum Operator {
 }
 
 #[derive(Debug)]
-struct Calibration {
+pub struct Calibration {
     target: u64,
     values: Vec<u64>,
 }
 
-fn main() {
-    let input = read_input("day7");
-    let cals = parse_input(&input).unwrap().1;
-    println!("Part 1: {}", total_calibration_result(&cals, false));
-    println!("Part 2: {}", total_calibration_result(&cals, true));
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Calibration>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLE: &'static str = concat!(
+        "190: 10 19\n",
+        "3267: 81 40 27\n",
+        "83: 17 5\n",
+        "156: 15 6\n",
+        "7290: 6 8 6 15\n",
+        "161011: 16 10 13\n",
+        "192: 17 8 14\n",
+        "21037: 9 7 18 13\n",
+        "292: 11 6 16 20\n",
+    );
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_all(input, parse_input)
+    }
+
+    fn part1(cals: &Self::Input) -> u64 {
+        total_calibration_result(cals, false)
+    }
+
+    fn part2(cals: &Self::Input) -> u64 {
+        total_calibration_result(cals, true)
+    }
 }
 
-fn total_calibration_result(cals: &[Calibration], concat: bool) -> u64 {
+/// Sums the targets of the calibrations that can be made true with `+` and
+/// `*`, and also `||` if `concat` is set.
+pub fn total_calibration_result(cals: &[Calibration], concat: bool) -> u64 {
     let mut available_operators = HashSet::new();
     available_operators.insert(Operator::Add);
     available_operators.insert(Operator::Multiply);
@@ -54,7 +88,7 @@ fn parse_calibration(input: &str) -> IResult<&str, Calibration> {
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<Calibration>> {
-    many1(terminated(parse_calibration, complete::newline))(input)
+    many1(terminated(parse_calibration, line_end))(input)
 }
 
 impl Operator {
@@ -99,31 +133,27 @@ impl Calibration {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use crate::solution::Solution;
 
-    use crate::{parse_input, total_calibration_result, Calibration, Operator};
+    use std::collections::HashSet;
 
-    const INPUT: &str = concat!(
-        "190: 10 19\n",
-        "3267: 81 40 27\n",
-        "83: 17 5\n",
-        "156: 15 6\n",
-        "7290: 6 8 6 15\n",
-        "161011: 16 10 13\n",
-        "192: 17 8 14\n",
-        "21037: 9 7 18 13\n",
-        "292: 11 6 16 20\n",
-    );
+    use super::{parse_input, total_calibration_result, Calibration, Day7, Operator};
 
     #[test]
     fn test_part_1() {
-        let cals = parse_input(INPUT).unwrap().1;
+        let cals = parse_input(Day7::EXAMPLE).unwrap().1;
+        assert_eq!(total_calibration_result(&cals, false), 3749);
+    }
+
+    #[test]
+    fn test_without_final_newline() {
+        let cals = Day7::parse(Day7::EXAMPLE.trim_end()).unwrap();
         assert_eq!(total_calibration_result(&cals, false), 3749);
     }
 
     #[test]
     fn test_part_2() {
-        let cals = parse_input(INPUT).unwrap().1;
+        let cals = parse_input(Day7::EXAMPLE).unwrap().1;
         assert_eq!(total_calibration_result(&cals, true), 11387);
     }
 
@@ -0,0 +1,164 @@
+//! Day 8: Resonant Collinearity
+
+use std::collections::{HashMap, HashSet};
+
+use itertools::{iproduct, Itertools};
+
+use crate::{
+    containers::Vec2D,
+    coord::{BoundedCoord2D, Coord2D},
+    error::Result,
+    solution::Solution,
+};
+
+pub type Frequency = char;
+pub type Antennas = HashSet<BoundedCoord2D>;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = HashMap<Frequency, Antennas>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const EXAMPLE: &'static str = concat!(
+        "............\n",
+        "........0...\n",
+        ".....0......\n",
+        ".......0....\n",
+        "....0.......\n",
+        "......A.....\n",
+        "............\n",
+        "............\n",
+        "........A...\n",
+        ".........A..\n",
+        "............\n",
+        "............\n",
+    );
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part1(antennas: &Self::Input) -> usize {
+        antinodes(antennas).len()
+    }
+
+    fn part2(antennas: &Self::Input) -> usize {
+        antinodes_with_resonance(antennas).len()
+    }
+}
+
+/// Returns the antinodes of all frequencies.
+pub fn antinodes(antennas: &HashMap<Frequency, Antennas>) -> HashSet<Coord2D> {
+    antennas.values().flat_map(antinodes_of).collect()
+}
+
+/// Returns the antinodes of all frequencies, taking resonant harmonics
+/// into account.
+pub fn antinodes_with_resonance(antennas: &HashMap<Frequency, Antennas>) -> HashSet<Coord2D> {
+    antennas
+        .values()
+        .flat_map(antinodes_with_resonance_of)
+        .collect()
+}
+
+/// Returns the antinodes of a single frequency.
+pub fn antinodes_of(antennas: &Antennas) -> HashSet<Coord2D> {
+    //     0  1  2  3  4  5  6  7  8  9
+    //   +-----------------------------
+    // 0 | .  .  .  .  .  .  .  .  .  .
+    // 1 | .  .  .  R  .  .  .  .  .  .
+    // 2 | .  .  .  .  .  .  .  .  .  .
+    // 3 | .  .  .  .  b  .  .  .  .  .
+    // 4 | .  .  .  .  .  .  .  .  .  .
+    // 5 | .  .  .  .  .  a  .  .  .  .
+    // 6 | .  .  .  .  .  .  .  .  .  .
+    // 7 | .  .  .  .  .  .  .  .  .  .
+    // 8 | .  .  .  .  .  .  .  .  .  .
+    // 9 | .  .  .  .  .  .  .  .  .  .
+    //
+    // Have:
+    //   5; 5 [a]
+    //   4; 3 [b]
+    //
+    // Want:
+    //   3; 1 [R]
+    //
+    // [b] - [a] [d]: vector from [b] to [a]
+    //   -2; -4
+    //
+    // [d] * 2: vector pointing in the direction from [b] to [a], but twice
+    //          as long
+    iproduct!(antennas.iter(), antennas.iter())
+        .filter(|(a, b)| a != b)
+        .filter_map(|(&a, &b)| a + ((b - a) * 2))
+        .map(|b| b.unbounded())
+        .collect()
+}
+
+/// Returns the antinodes of a single frequency, taking resonant harmonics
+/// into account.
+pub fn antinodes_with_resonance_of(antennas: &Antennas) -> HashSet<Coord2D> {
+    antennas
+        .iter()
+        .tuple_combinations()
+        .flat_map(|(a, b)| a.bounds().line_through(a.unbounded(), b.unbounded()))
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<HashMap<Frequency, Antennas>> {
+    let map: Vec2D<char> = input.parse()?;
+    let mut antennas = HashMap::new();
+
+    for (index, &frequency) in map.enumerated_iter().filter(|(_, &c)| c != '.') {
+        antennas
+            .entry(frequency)
+            .or_insert_with(HashSet::new)
+            .insert(map.bounded(&index).unwrap());
+    }
+
+    Ok(antennas)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashMap, HashSet};
+
+    use crate::{
+        coord::{BoundedCoord2D, Bounds2D, Coord2D},
+        solution::Solution,
+    };
+
+    use super::{antinodes, antinodes_with_resonance, parse_input, Day8};
+
+    #[test]
+    fn test_parse() {
+        let ant = parse_input(Day8::EXAMPLE).unwrap();
+        assert_eq!(
+            ant,
+            HashMap::from([
+                ('0', HashSet::from([mk(8, 1), mk(5, 2), mk(7, 3), mk(4, 4)])),
+                ('A', HashSet::from([mk(6, 5), mk(8, 8), mk(9, 9)]))
+            ])
+        );
+
+        fn mk(x: u32, y: u32) -> BoundedCoord2D {
+            BoundedCoord2D::new(Coord2D::new(x, y), Bounds2D::new(12, 12))
+        }
+    }
+
+    #[test]
+    fn test_part_1() {
+        let antennas = parse_input(Day8::EXAMPLE).unwrap();
+        dbg!(antinodes(&antennas));
+        assert_eq!(antinodes(&antennas).len(), 14);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let antennas = parse_input(Day8::EXAMPLE).unwrap();
+        dbg!(antinodes(&antennas));
+        assert_eq!(antinodes_with_resonance(&antennas).len(), 34);
+    }
+}
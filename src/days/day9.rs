@@ -1,37 +1,56 @@
+//! Day 9: Disk Fragmenter
+
 use std::{fmt::Write, iter::repeat_n};
 
-use aoc_2024::read_input;
 use nom::{character::complete, multi::fold_many0, IResult};
 use std::iter::RepeatN;
 
+use crate::{
+    error::{non_empty, parse_all, Result},
+    solution::Solution,
+};
+
 #[derive(PartialEq, Clone, Copy)]
-enum DiskBlockType {
+pub enum DiskBlockType {
     Free,
     File(u64),
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
-struct DiskBlock {
+pub struct DiskBlock {
     length: u32,
     block_type: DiskBlockType,
 }
 
-fn main() {
-    let input = read_input("day9");
-    let fragged = parse_input(&input).unwrap().1;
-    println!(
-        "Part 1: {}",
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<DiskBlock>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    const EXAMPLE: &'static str = "2333133121414131402";
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        parse_all(non_empty(input)?, parse_input)
+    }
+
+    fn part1(fragged: &Self::Input) -> u64 {
         compressed_checksum(
             &fragged
                 .iter()
                 .flat_map(|p| p.into_iter())
-                .collect::<Vec<_>>()
+                .collect::<Vec<_>>(),
         )
-    );
-    println!("Part 2: {}", defragged_checksum(&fragged));
+    }
+
+    fn part2(fragged: &Self::Input) -> u64 {
+        defragged_checksum(fragged)
+    }
 }
 
-fn compressed_checksum(fragged: &[DiskBlockType]) -> u64 {
+/// Checksum after moving single blocks from the end into free space.
+pub fn compressed_checksum(fragged: &[DiskBlockType]) -> u64 {
     let mut checksum = 0;
     let mut fragged = Vec::from(fragged);
     let mut indx = 0;
@@ -70,7 +89,9 @@ fn compressed_checksum(fragged: &[DiskBlockType]) -> u64 {
     checksum
 }
 
-fn defragged_checksum(blocks: &[DiskBlock]) -> u64 {
+/// Checksum after moving whole files into the leftmost free space they
+/// fit into.
+pub fn defragged_checksum(blocks: &[DiskBlock]) -> u64 {
     let mut checksum = 0;
     let mut fragged = Vec::from(blocks);
     let mut mul = 0;
@@ -79,13 +100,10 @@ fn defragged_checksum(blocks: &[DiskBlock]) -> u64 {
         let mut newfragged = Vec::from(newfragged);
         match block.block_type {
             DiskBlockType::Free => {
-                if let Some((mut remove_index, last_used)) = newfragged
-                    .iter()
-                    .enumerate()
-                    .filter(|(_, b)| {
+                if let Some((mut remove_index, last_used)) =
+                    newfragged.iter().enumerate().rfind(|(_, b)| {
                         matches!(b.block_type, DiskBlockType::File(_)) && b.length <= block.length
                     })
-                    .last()
                 {
                     match last_used.block_type {
                         DiskBlockType::Free => panic!(),
@@ -179,13 +197,15 @@ impl IntoIterator for DiskBlock {
 
 #[cfg(test)]
 mod tests {
-    use crate::{compressed_checksum, defragged_checksum, parse_input, DiskBlock, DiskBlockType};
+    use crate::{error::Error, solution::Solution};
 
-    const INPUT: &str = "2333133121414131402";
+    use super::{
+        compressed_checksum, defragged_checksum, parse_input, Day9, DiskBlock, DiskBlockType,
+    };
 
     #[test]
     fn test_part_1() {
-        let fragged = parse_input(INPUT).unwrap().1;
+        let fragged = parse_input(Day9::EXAMPLE).unwrap().1;
         assert_eq!(
             compressed_checksum(
                 &fragged
@@ -199,7 +219,7 @@ mod tests {
 
     #[test]
     fn test_part_2() {
-        let fragged = parse_input(INPUT).unwrap().1;
+        let fragged = parse_input(Day9::EXAMPLE).unwrap().1;
         assert_eq!(defragged_checksum(&fragged), 2858);
     }
 
@@ -232,4 +252,10 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_empty_input() {
+        assert!(matches!(Day9::parse(""), Err(Error::InvalidInput(_))));
+        assert!(matches!(Day9::parse("\n"), Err(Error::InvalidInput(_))));
+    }
 }
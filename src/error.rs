@@ -0,0 +1,257 @@
+use std::{fmt, io, path::PathBuf};
+
+use nom::{
+    branch::alt,
+    character::complete,
+    combinator::{eof, value},
+    error::ErrorKind,
+    IResult,
+};
+
+use crate::containers::Vec2DError;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The input file does not exist.
+    MissingInput(PathBuf),
+    /// The input file exists but could not be read.
+    Io(PathBuf, io::Error),
+    /// The input text is malformed. Line and column are 1-based.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// The input is well-formed but does not describe a solvable puzzle.
+    InvalidInput(String),
+    /// Downloading an input failed.
+    Fetch(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    /// Creates a parse error located at the start of `fragment`, which must be
+    /// a subslice of `input`.
+    pub fn parse_at(input: &str, fragment: &str, message: impl fmt::Display) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&o| o <= input.len())
+            .expect("fragment is not part of input");
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Self::Parse {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn invalid_input(message: impl fmt::Display) -> Self {
+        Self::InvalidInput(message.to_string())
+    }
+
+    fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => {
+                Self::parse_at(input, &input[input.len()..], "unexpected end of input")
+            }
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                let found = match e.input.chars().next() {
+                    Some('\n') => "newline".to_string(),
+                    Some(c) => format!("'{c}'"),
+                    None => "end of input".to_string(),
+                };
+                let message = format!("{}, found {found}", Self::describe(e.code));
+                Self::parse_at(input, e.input, message)
+            }
+        }
+    }
+
+    /// Describes what the nom parser that failed with `kind` was looking for.
+    fn describe(kind: ErrorKind) -> &'static str {
+        match kind {
+            ErrorKind::Digit | ErrorKind::Float => "expected a number",
+            ErrorKind::Eof | ErrorKind::CrLf => "expected newline",
+            ErrorKind::Space | ErrorKind::MultiSpace => "expected whitespace",
+            ErrorKind::Alpha | ErrorKind::AlphaNumeric => "expected a letter",
+            ErrorKind::Many1 | ErrorKind::Many1Count | ErrorKind::SeparatedList => {
+                "expected at least one entry"
+            }
+            ErrorKind::Tag | ErrorKind::Char => "expected a separator",
+            ErrorKind::OneOf | ErrorKind::NoneOf | ErrorKind::Satisfy => {
+                "expected a valid character"
+            }
+            _ => "invalid syntax",
+        }
+    }
+}
+
+/// Runs `parser` on `input` and fails unless everything but trailing
+/// whitespace was consumed.
+pub fn parse_all<'a, O>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> Result<O> {
+    let (rest, out) = parser(input).map_err(|e| Error::from_nom(input, e))?;
+
+    if rest.trim().is_empty() {
+        Ok(out)
+    } else {
+        Err(Error::parse_at(input, rest, "unexpected trailing input"))
+    }
+}
+
+/// Fails if `input` is empty or only whitespace, as is left by a failed or
+/// truncated download.
+pub fn non_empty(input: &str) -> Result<&str> {
+    if input.trim().is_empty() {
+        Err(Error::invalid_input("the input is empty"))
+    } else {
+        Ok(input)
+    }
+}
+
+/// Matches the end of a line, which may be the end of the input as the final
+/// newline is often missing when input is pasted.
+pub fn line_end(input: &str) -> IResult<&str, ()> {
+    alt((value((), complete::newline), value((), eof)))(input)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput(path) => write!(f, "missing input file {}", path.display()),
+            Error::Io(path, err) => write!(f, "could not read {}: {err}", path.display()),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "parse error at line {line}, column {column}: {message}"),
+            Error::InvalidInput(message) => write!(f, "invalid puzzle data: {message}"),
+            Error::Fetch(message) => write!(f, "could not fetch input: {message}"),
+        }
+    }
+}
+
+impl From<Vec2DError> for Error {
+    fn from(err: Vec2DError) -> Self {
+        match err {
+            Vec2DError::Empty => Error::Parse {
+                line: 1,
+                column: 1,
+                message: err.to_string(),
+            },
+            Vec2DError::Ragged {
+                line,
+                expected,
+                actual,
+            } => Error::Parse {
+                line,
+                column: expected.min(actual) + 1,
+                message: err.to_string(),
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::{
+        character::complete,
+        multi::separated_list1,
+        sequence::{separated_pair, terminated, tuple},
+    };
+
+    use nom::IResult;
+
+    use crate::error::{line_end, non_empty, parse_all, Error};
+
+    #[test]
+    fn test_parse_at() {
+        let input = "ab\ncde\nf";
+        match Error::parse_at(input, &input[5..], "oops") {
+            Error::Parse { line, column, .. } => assert_eq!((line, column), (2, 3)),
+            e => panic!("unexpected error {e}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let parser = |i| separated_list1(complete::char(','), complete::u32)(i);
+        assert_eq!(parse_all("1,2,3\n", parser).unwrap(), vec![1, 2, 3]);
+        assert!(matches!(
+            parse_all("1,2\n3", parser),
+            Err(Error::Parse {
+                line: 1,
+                column: 4,
+                ..
+            })
+        ));
+        let line = |i| terminated(complete::u32, line_end)(i);
+        assert_eq!(parse_all("12\n", line).unwrap(), 12);
+        assert_eq!(parse_all("12", line).unwrap(), 12);
+        assert!(parse_all("12x", line).is_err());
+
+        assert!(matches!(
+            parse_all("x", parser),
+            Err(Error::Parse {
+                line: 1,
+                column: 1,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_non_empty() {
+        assert_eq!(non_empty("1\n").unwrap(), "1\n");
+        assert!(matches!(non_empty(""), Err(Error::InvalidInput(_))));
+        assert!(matches!(non_empty(" \n\n"), Err(Error::InvalidInput(_))));
+    }
+
+    /// Parses `input` with `parser` and returns the error message.
+    fn message<'a, O: std::fmt::Debug>(
+        input: &'a str,
+        parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+    ) -> String {
+        parse_all(input, parser).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_parse_messages() {
+        let line = |i| terminated(complete::u32, line_end)(i);
+        let pair = |i| separated_pair(complete::u32, complete::char('|'), complete::u32)(i);
+
+        assert_eq!(
+            message("x", complete::u32),
+            "parse error at line 1, column 1: expected a number, found 'x'"
+        );
+        assert_eq!(
+            message("", complete::u32),
+            "parse error at line 1, column 1: expected a number, found end of input"
+        );
+        assert_eq!(
+            message("12x", line),
+            "parse error at line 1, column 3: expected newline, found 'x'"
+        );
+        assert_eq!(
+            message("3-4", pair),
+            "parse error at line 1, column 2: expected a separator, found '-'"
+        );
+        assert_eq!(
+            message("12\n3x", |i| tuple((line, line))(i)),
+            "parse error at line 2, column 2: expected newline, found 'x'"
+        );
+    }
+}
@@ -0,0 +1,292 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use regex::Regex;
+
+use crate::{
+    answers::Part,
+    error::{Error, Result},
+    input::input_dir,
+};
+
+/// Environment variable holding the session token.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable naming a file holding the session token, used if
+/// [`SESSION_VAR`] is not set.
+pub const SESSION_FILE_VAR: &str = "AOC_SESSION_FILE";
+/// Session token file used if neither [`SESSION_VAR`] nor
+/// [`SESSION_FILE_VAR`] is set.
+pub const DEFAULT_SESSION_FILE: &str = ".aoc-session";
+/// Environment variable overriding [`DEFAULT_BASE_URL`].
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const YEAR: u32 = 2024;
+
+/// Downloads puzzle inputs into the input cache and submits answers.
+pub struct Fetcher {
+    base_url: String,
+    /// The session token, or why there is none. It is only needed once
+    /// something has to be sent to the server.
+    session: std::result::Result<String, String>,
+    cache_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already cached and has not been downloaded again.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// The server's response to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    /// `hint` tells whether the answer is too high or too low, if the server
+    /// said so.
+    Wrong {
+        hint: Option<String>,
+    },
+    /// Another answer was submitted too recently, `wait` is the remaining
+    /// time as reported by the server.
+    TooSoon {
+        wait: Option<String>,
+    },
+    /// The part is either already solved or not unlocked yet.
+    WrongLevel,
+    /// The response could not be interpreted, holds its text.
+    Unrecognized(String),
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, cache_dir: impl AsRef<Path>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: Ok(session.trim().to_string()),
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Creates a fetcher configured from the environment, caching into the
+    /// [`input_dir`]. A missing session token is only reported once a
+    /// request has to be made.
+    pub fn from_env() -> Self {
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let session = env::var(SESSION_VAR).or_else(|_| {
+            let path = env::var_os(SESSION_FILE_VAR)
+                .map_or_else(|| PathBuf::from(DEFAULT_SESSION_FILE), PathBuf::from);
+            fs::read_to_string(&path).map_err(|e| {
+                format!(
+                    "no session token: set {SESSION_VAR} or store it in {} ({e})",
+                    path.display()
+                )
+            })
+        });
+
+        Self {
+            session: session.map(|s| s.trim().to_string()),
+            ..Self::new(&base_url, "", input_dir())
+        }
+    }
+
+    /// Returns the path `day` is cached at.
+    pub fn cache_path(&self, day: u32) -> PathBuf {
+        self.cache_dir.join(format!("day{day}"))
+    }
+
+    /// Makes sure the input of `day` is cached, downloading it if it is not.
+    pub fn fetch(&self, day: u32) -> Result<Fetched> {
+        let path = self.cache_path(day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = format!("{}/{YEAR}/day/{day}/input", self.base_url);
+        let input = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .set("User-Agent", "github.com/424ever/aoc-2024")
+            .call()
+            .map_err(|e| Error::Fetch(e.to_string()))?
+            .into_string()
+            .map_err(|e| Error::Fetch(format!("{url}: {e}")))?;
+
+        fs::create_dir_all(&self.cache_dir).map_err(|e| Error::Io(self.cache_dir.clone(), e))?;
+        fs::write(&path, input).map_err(|e| Error::Io(path.clone(), e))?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    pub fn submit(&self, day: u32, part: Part, answer: &str) -> Result<Verdict> {
+        let url = format!("{}/{YEAR}/day/{day}/answer", self.base_url);
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .set("User-Agent", "github.com/424ever/aoc-2024")
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|e| Error::Fetch(e.to_string()))?
+            .into_string()
+            .map_err(|e| Error::Fetch(format!("{url}: {e}")))?;
+
+        Ok(Verdict::from_response(&response))
+    }
+
+    fn session(&self) -> Result<&str> {
+        self.session
+            .as_deref()
+            .map_err(|message| Error::Fetch(message.clone()))
+    }
+}
+
+impl Verdict {
+    /// Interprets the HTML page returned after submitting an answer.
+    pub fn from_response(body: &str) -> Self {
+        let article = body
+            .split_once("<article")
+            .and_then(|(_, a)| a.split_once('>'))
+            .and_then(|(_, a)| a.split_once("</article>"))
+            .map_or(body, |(a, _)| a);
+        let text = Regex::new("<[^>]*>").unwrap().replace_all(article, "");
+        let capture = |re: &str| {
+            Regex::new(re)
+                .unwrap()
+                .captures(&text)
+                .map(|c| c[1].to_string())
+        };
+
+        if text.contains("That's the right answer") {
+            Verdict::Correct
+        } else if text.contains("That's not the right answer") {
+            Verdict::Wrong {
+                hint: capture(r"your answer is (too \w+)"),
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Verdict::TooSoon {
+                wait: capture(r"You have (.+?) left to wait"),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            Verdict::Unrecognized(text.trim().to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        env, fs,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    use crate::{
+        error::Error,
+        fetch::{Fetched, Fetcher, Verdict},
+    };
+
+    #[test]
+    fn test_fetch_caches() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request.push(line.trim().to_string());
+            }
+            let body = "1 2 3\n";
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        let cache_dir = env::temp_dir().join(format!("aoc-2024-fetch-{}", std::process::id()));
+        let fetcher = Fetcher::new(&base_url, "secret\n", &cache_dir);
+        let path = fetcher.cache_path(3);
+
+        assert_eq!(fetcher.fetch(3).unwrap(), Fetched::Downloaded(path.clone()));
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2024/day/3/input HTTP/1.1");
+        assert!(request.contains(&"Cookie: session=secret".to_string()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+
+        // the server is gone, so this only succeeds without a request
+        assert_eq!(fetcher.fetch(3).unwrap(), Fetched::Cached(path));
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_cached_without_session() {
+        let cache_dir = env::temp_dir().join(format!("aoc-2024-nosession-{}", std::process::id()));
+        let fetcher = Fetcher {
+            session: Err("no session token".to_string()),
+            ..Fetcher::new("http://127.0.0.1:0", "", &cache_dir)
+        };
+        fs::create_dir_all(&cache_dir).unwrap();
+        fs::write(fetcher.cache_path(1), "1 2\n").unwrap();
+
+        assert_eq!(
+            fetcher.fetch(1).unwrap(),
+            Fetched::Cached(fetcher.cache_path(1))
+        );
+        assert!(matches!(fetcher.fetch(2), Err(Error::Fetch(m)) if m == "no session token"));
+
+        fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_verdict() {
+        assert_eq!(
+            Verdict::from_response(
+                "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main>"
+            ),
+            Verdict::Correct
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "<article><p>That's not the right answer; your answer is too low.  Please wait one minute.</p></article>"
+            ),
+            Verdict::Wrong {
+                hint: Some("too low".to_string())
+            }
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "<article><p>That's not the right answer.  If you're stuck, ...</p></article>"
+            ),
+            Verdict::Wrong { hint: None }
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "<article><p>You gave an answer too recently; you have to wait. You have 42s left to wait.</p></article>"
+            ),
+            Verdict::TooSoon {
+                wait: Some("42s".to_string())
+            }
+        );
+        assert_eq!(
+            Verdict::from_response(
+                "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>"
+            ),
+            Verdict::WrongLevel
+        );
+        assert_eq!(
+            Verdict::from_response("<article><p>Huh?</p></article>"),
+            Verdict::Unrecognized("Huh?".to_string())
+        );
+    }
+}
@@ -0,0 +1,521 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::{
+    containers::{Vec2D, Vec2DIndex},
+    coord::{Bounds2D, Coord2D, Direction2D, ToOffset2D},
+};
+
+/// A finite two-dimensional grid, regardless of how it is stored.
+pub trait Grid {
+    type Index: Copy + Eq + Hash;
+    type Item;
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// Not called `get`, as that would be shadowed by inherent methods with
+    /// other signatures, like [`Vec2D::get`] taking a line and a column.
+    fn cell(&self, index: &Self::Index) -> Option<&Self::Item>;
+
+    fn bounds(&self) -> Bounds2D;
+
+    /// Iterates over all positions in bounds, line by line.
+    fn positions(&self) -> impl Iterator<Item = Self::Index> + '_;
+
+    /// Returns the position next to `index` in direction `dir`, or `None` if
+    /// it is out of bounds.
+    fn step(&self, index: &Self::Index, dir: Direction2D) -> Option<Self::Index>;
+
+    /// Iterates over all positions in bounds along with their elements.
+    fn cells(&self) -> impl Iterator<Item = (Self::Index, &Self::Item)> + '_ {
+        self.positions().filter_map(|i| Some((i, self.cell(&i)?)))
+    }
+
+    /// Iterates over the horizontally and vertically adjacent elements that
+    /// are in bounds, tagged with the direction they are in.
+    fn neighbors4(
+        &self,
+        index: &Self::Index,
+    ) -> impl Iterator<Item = (Direction2D, Self::Index, &Self::Item)> + '_ {
+        let index = *index;
+        Direction2D::all().filter_map(move |dir| {
+            let neighbor = self.step(&index, dir)?;
+            Some((dir, neighbor, self.cell(&neighbor)?))
+        })
+    }
+}
+
+/// A grid storing only the elements that differ from a default value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    bounds: Bounds2D,
+    default: T,
+    cells: HashMap<Coord2D, T>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Creates a grid of the size `bounds` where every element is `default`.
+    pub fn new(bounds: Bounds2D, default: T) -> Self {
+        Self {
+            bounds,
+            default,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Sets the element at `coord`, returning the previously set one.
+    ///
+    /// Panics if `coord` is out of bounds.
+    pub fn insert(&mut self, coord: Coord2D, value: T) -> Option<T> {
+        assert!(
+            self.bounds.is_valid(&coord),
+            "{coord:?} out of bounds for {:?}",
+            self.bounds
+        );
+        self.cells.insert(coord, value)
+    }
+
+    /// Resets the element at `coord` to the default, returning the previously
+    /// set one.
+    pub fn remove(&mut self, coord: &Coord2D) -> Option<T> {
+        self.cells.remove(coord)
+    }
+
+    /// Iterates over the elements that have been set, in no particular order.
+    pub fn occupied(&self) -> impl Iterator<Item = (Coord2D, &T)> {
+        self.cells.iter().map(|(c, e)| (*c, e))
+    }
+}
+
+/// An unbounded grid with signed coordinates where every element is a
+/// default value until it is written. Positions on a bounded grid can be
+/// moved onto it with [`Coord2D::cast`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InfiniteGrid<T> {
+    default: T,
+    cells: HashMap<Coord2D<i64>, T>,
+    extent: Option<(Coord2D<i64>, Coord2D<i64>)>,
+}
+
+impl<T> InfiniteGrid<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            default,
+            cells: HashMap::new(),
+            extent: None,
+        }
+    }
+
+    pub fn get(&self, coord: &Coord2D<i64>) -> &T {
+        self.cells.get(coord).unwrap_or(&self.default)
+    }
+
+    /// Sets the element at `coord`, returning the previously set one.
+    pub fn insert(&mut self, coord: Coord2D<i64>, value: T) -> Option<T> {
+        self.grow(coord);
+        self.cells.insert(coord, value)
+    }
+
+    /// The smallest and largest coordinate of the bounding box of all
+    /// elements that have been written, or `None` if there are none.
+    pub fn extent(&self) -> Option<(Coord2D<i64>, Coord2D<i64>)> {
+        self.extent
+    }
+
+    /// Iterates over the elements that have been written, in no particular
+    /// order.
+    pub fn occupied(&self) -> impl Iterator<Item = (Coord2D<i64>, &T)> {
+        self.cells.iter().map(|(c, e)| (*c, e))
+    }
+
+    fn grow(&mut self, coord: Coord2D<i64>) {
+        let (min, max) = self.extent.unwrap_or((coord, coord));
+        self.extent = Some((
+            Coord2D::new(min.x().min(coord.x()), min.y().min(coord.y())),
+            Coord2D::new(max.x().max(coord.x()), max.y().max(coord.y())),
+        ));
+    }
+}
+
+impl<T: Clone> InfiniteGrid<T> {
+    /// Returns the element at `coord` for writing, starting out as the
+    /// default.
+    pub fn get_mut(&mut self, coord: Coord2D<i64>) -> &mut T {
+        self.grow(coord);
+        self.cells
+            .entry(coord)
+            .or_insert_with(|| self.default.clone())
+    }
+}
+
+/// A set of positions within `Bounds2D`, stored as one bit per position.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    bounds: Bounds2D,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// Creates an empty set of positions within `bounds`.
+    pub fn new(bounds: Bounds2D) -> Self {
+        let bits = bounds.width() as usize * bounds.height() as usize;
+        Self {
+            bounds,
+            words: vec![0; bits.div_ceil(64)],
+        }
+    }
+
+    /// Creates a set of the positions in `coords`, see [`BitGrid::insert`].
+    pub fn from_coords(bounds: Bounds2D, coords: impl IntoIterator<Item = Coord2D>) -> Self {
+        let mut grid = Self::new(bounds);
+        grid.extend(coords);
+        grid
+    }
+
+    pub fn bounds(&self) -> Bounds2D {
+        self.bounds
+    }
+
+    /// Whether `coord` is in the set. Positions out of bounds never are.
+    pub fn contains(&self, coord: &Coord2D) -> bool {
+        self.bit(coord)
+            .is_some_and(|(word, mask)| self.words[word] & mask != 0)
+    }
+
+    /// Adds `coord`, returning whether it was not in the set before.
+    ///
+    /// Panics if `coord` is out of bounds.
+    pub fn insert(&mut self, coord: Coord2D) -> bool {
+        let (word, mask) = self.bit_in_bounds(&coord);
+        let absent = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        absent
+    }
+
+    /// Removes `coord`, returning whether it was in the set.
+    pub fn remove(&mut self, coord: &Coord2D) -> bool {
+        let Some((word, mask)) = self.bit(coord) else {
+            return false;
+        };
+        let present = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        present
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// The number of positions in the set.
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    /// Adds all positions of `other`.
+    ///
+    /// Panics if the bounds of both sets differ.
+    pub fn union_with(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a | b);
+    }
+
+    /// Removes all positions not in `other`.
+    ///
+    /// Panics if the bounds of both sets differ.
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a & b);
+    }
+
+    /// Removes all positions in `other`.
+    ///
+    /// Panics if the bounds of both sets differ.
+    pub fn difference_with(&mut self, other: &BitGrid) {
+        self.combine(other, |a, b| a & !b);
+    }
+
+    /// Iterates over the positions in the set, line by line.
+    pub fn iter(&self) -> impl Iterator<Item = Coord2D> + '_ {
+        let width = self.bounds.width() as usize;
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            (0..64)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| {
+                    let pos = i * 64 + bit;
+                    Coord2D::new((pos % width) as u32, (pos / width) as u32)
+                })
+        })
+    }
+
+    fn bit(&self, coord: &Coord2D) -> Option<(usize, u64)> {
+        if !self.bounds.is_valid(coord) {
+            return None;
+        }
+        let pos = coord.y() as usize * self.bounds.width() as usize + coord.x() as usize;
+        Some((pos / 64, 1 << (pos % 64)))
+    }
+
+    fn bit_in_bounds(&self, coord: &Coord2D) -> (usize, u64) {
+        self.bit(coord)
+            .unwrap_or_else(|| panic!("{coord:?} out of bounds for {:?}", self.bounds))
+    }
+
+    fn combine(&mut self, other: &BitGrid, op: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            self.bounds, other.bounds,
+            "combining sets of different bounds"
+        );
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a = op(*a, *b);
+        }
+    }
+}
+
+impl Extend<Coord2D> for BitGrid {
+    fn extend<I: IntoIterator<Item = Coord2D>>(&mut self, iter: I) {
+        for coord in iter {
+            self.insert(coord);
+        }
+    }
+}
+
+impl<T> Grid for Vec2D<T> {
+    type Index = Vec2DIndex;
+    type Item = T;
+
+    fn cell(&self, index: &Vec2DIndex) -> Option<&T> {
+        self.get_index(index)
+    }
+
+    fn bounds(&self) -> Bounds2D {
+        Vec2D::bounds(self)
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Vec2DIndex> + '_ {
+        self.indizes()
+    }
+
+    fn step(&self, index: &Vec2DIndex, dir: Direction2D) -> Option<Vec2DIndex> {
+        (*index + dir.to_offset()).filter(|i| self.contains(i))
+    }
+
+    fn neighbors4(
+        &self,
+        index: &Vec2DIndex,
+    ) -> impl Iterator<Item = (Direction2D, Vec2DIndex, &T)> + '_ {
+        Vec2D::neighbors4(self, index)
+    }
+}
+
+impl<T> Grid for SparseGrid<T> {
+    type Index = Coord2D;
+    type Item = T;
+
+    fn cell(&self, coord: &Coord2D) -> Option<&T> {
+        if self.bounds.is_valid(coord) {
+            Some(self.cells.get(coord).unwrap_or(&self.default))
+        } else {
+            None
+        }
+    }
+
+    fn bounds(&self) -> Bounds2D {
+        self.bounds
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Coord2D> + '_ {
+        self.bounds.positions()
+    }
+
+    fn step(&self, coord: &Coord2D, dir: Direction2D) -> Option<Coord2D> {
+        coord.go_in(&dir).filter(|c| self.bounds.is_valid(c))
+    }
+}
+
+impl Grid for BitGrid {
+    type Index = Coord2D;
+    type Item = bool;
+
+    fn cell(&self, coord: &Coord2D) -> Option<&bool> {
+        self.bit(coord).map(|(word, mask)| {
+            if self.words[word] & mask != 0 {
+                &true
+            } else {
+                &false
+            }
+        })
+    }
+
+    fn bounds(&self) -> Bounds2D {
+        self.bounds
+    }
+
+    fn positions(&self) -> impl Iterator<Item = Coord2D> + '_ {
+        self.bounds.positions()
+    }
+
+    fn step(&self, coord: &Coord2D, dir: Direction2D) -> Option<Coord2D> {
+        coord.go_in(&dir).filter(|c| self.bounds.is_valid(c))
+    }
+}
+
+/// Returns all positions reachable from `start` by moving horizontally or
+/// vertically between elements for which `connected` holds.
+pub fn flood_fill<G: Grid>(
+    grid: &G,
+    start: G::Index,
+    connected: impl Fn(&G::Item, &G::Item) -> bool,
+) -> HashSet<G::Index> {
+    bfs(grid, start, connected).into_keys().collect()
+}
+
+/// Returns the number of steps to every position reachable from `start` by
+/// moving horizontally or vertically from one element to another for which
+/// `passable` holds.
+pub fn bfs<G: Grid>(
+    grid: &G,
+    start: G::Index,
+    passable: impl Fn(&G::Item, &G::Item) -> bool,
+) -> HashMap<G::Index, usize> {
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut worklist = VecDeque::from([start]);
+
+    while let Some(pos) = worklist.pop_front() {
+        let (Some(cur), Some(&dist)) = (grid.cell(&pos), distances.get(&pos)) else {
+            continue;
+        };
+        for (_, next, item) in grid.neighbors4(&pos) {
+            if passable(cur, item) && !distances.contains_key(&next) {
+                distances.insert(next, dist + 1);
+                worklist.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{
+        containers::{Vec2D, Vec2DIndex},
+        coord::{Bounds2D, Coord2D, Direction2D},
+        grid::{bfs, flood_fill, BitGrid, Grid, InfiniteGrid, SparseGrid},
+    };
+
+    #[test]
+    fn test_bit_grid() {
+        let bounds = Bounds2D::new(9, 9);
+        let mut a = BitGrid::new(bounds);
+        assert!(a.is_empty());
+        assert!(a.insert(Coord2D::new(8, 8)));
+        assert!(!a.insert(Coord2D::new(8, 8)));
+        a.extend([Coord2D::new(0, 0), Coord2D::new(1, 7)]);
+        assert!(a.contains(&Coord2D::new(1, 7)));
+        assert!(!a.contains(&Coord2D::new(7, 1)));
+        assert!(!a.contains(&Coord2D::new(9, 0)));
+        assert_eq!(a.len(), 3);
+        assert_eq!(
+            a.iter().collect::<Vec<_>>(),
+            [Coord2D::new(0, 0), Coord2D::new(1, 7), Coord2D::new(8, 8)]
+        );
+        assert_eq!(a.cell(&Coord2D::new(0, 0)), Some(&true));
+
+        let b = BitGrid::from_coords(bounds, [Coord2D::new(1, 7), Coord2D::new(4, 4)]);
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union.len(), 4);
+        let mut intersection = a.clone();
+        intersection.intersect_with(&b);
+        assert_eq!(
+            intersection.iter().collect::<Vec<_>>(),
+            [Coord2D::new(1, 7)]
+        );
+        a.difference_with(&b);
+        assert_eq!(a.len(), 2);
+
+        assert!(a.remove(&Coord2D::new(0, 0)));
+        assert!(!a.remove(&Coord2D::new(0, 0)));
+        a.clear();
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn test_infinite_grid() {
+        let mut grid = InfiniteGrid::new(0);
+        assert_eq!(grid.extent(), None);
+        assert_eq!(grid.get(&Coord2D::new(-5, 7)), &0);
+
+        grid.insert(Coord2D::new(-2, 3), 1);
+        *grid.get_mut(Coord2D::new(4, -1)) += 2;
+        assert_eq!(grid.get(&Coord2D::new(4, -1)), &2);
+        assert_eq!(
+            grid.extent(),
+            Some((Coord2D::new(-2, -1), Coord2D::new(4, 3)))
+        );
+        assert_eq!(grid.occupied().count(), 2);
+
+        let west = Coord2D::new(0u32, 1)
+            .cast::<i64>()
+            .go_in(&Direction2D::West);
+        assert_eq!(west, Some(Coord2D::new(-1, 1)));
+        grid.insert(Coord2D::new(u32::MAX, 0).cast(), 3);
+        assert_eq!(grid.get(&Coord2D::new(i64::from(u32::MAX), 0)), &3);
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new(Bounds2D::new(3, 2), '.');
+        assert_eq!(grid.insert(Coord2D::new(1, 1), '#'), None);
+        assert_eq!(grid.cell(&Coord2D::new(1, 1)), Some(&'#'));
+        assert_eq!(grid.cell(&Coord2D::new(2, 1)), Some(&'.'));
+        assert_eq!(grid.cell(&Coord2D::new(3, 1)), None);
+        assert_eq!(grid.cells().count(), 6);
+        assert_eq!(
+            grid.neighbors4(&Coord2D::new(1, 0))
+                .map(|(d, _, c)| (d, *c))
+                .collect::<Vec<_>>(),
+            [
+                (Direction2D::South, '#'),
+                (Direction2D::East, '.'),
+                (Direction2D::West, '.')
+            ]
+        );
+        assert_eq!(grid.remove(&Coord2D::new(1, 1)), Some('#'));
+        assert_eq!(grid.occupied().count(), 0);
+    }
+
+    #[test]
+    fn test_algorithms_on_both_grids() {
+        let dense: Vec2D<char> = "..#\n.##\n...\n".parse().unwrap();
+        let mut sparse = SparseGrid::new(dense.bounds(), '.');
+        for (i, _) in dense.cells().filter(|(_, &c)| c == '#') {
+            sparse.insert(Coord2D::try_from(i).unwrap(), '#');
+        }
+
+        let center = Vec2DIndex::new(1, 1);
+        assert!(Grid::neighbors4(&dense, &center).eq(dense.neighbors4(&center)));
+
+        let open = |_: &char, b: &char| *b == '.';
+        let distances = bfs(&dense, Vec2DIndex::new(0, 0), open);
+        assert_eq!(distances.len(), 6);
+        assert_eq!(distances[&Vec2DIndex::new(2, 2)], 4);
+        assert_eq!(bfs(&sparse, Coord2D::new(0, 0), open).len(), 6);
+
+        let walls = flood_fill(&sparse, Coord2D::new(2, 0), |a, b| a == b);
+        assert_eq!(
+            walls,
+            HashSet::from([Coord2D::new(2, 0), Coord2D::new(1, 1), Coord2D::new(2, 1)])
+        );
+        assert_eq!(
+            flood_fill(&dense, Vec2DIndex::new(0, 2), |a, b| a == b).len(),
+            3
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::error::{Error, Result};
+
+/// Environment variable overriding the directory puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Returns the directory puzzle inputs are read from: `$AOC_INPUT_DIR` if set,
+/// `inputs` otherwise.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR).map_or_else(|| PathBuf::from("inputs"), PathBuf::from)
+}
+
+/// Reads the input called `name` from the [`input_dir`].
+pub fn read_input(name: &str) -> Result<String> {
+    read_file(input_dir().join(name))
+}
+
+pub fn read_file(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    fs::read_to_string(path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => Error::MissingInput(path.to_path_buf()),
+        _ => Error::Io(path.to_path_buf(), e),
+    })
+}
+
+pub fn read_stdin() -> Result<String> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|e| Error::Io(PathBuf::from("<stdin>"), e))?;
+    Ok(input)
+}
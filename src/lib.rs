@@ -1,11 +1,15 @@
-use std::fs;
-
+pub mod answers;
 pub mod containers;
 pub mod coord;
+pub mod days;
+pub mod error;
+pub mod fetch;
+pub mod grid;
+pub mod input;
 pub mod iters;
+pub mod render;
+pub mod solution;
+pub mod timing;
 pub mod util;
 
-pub fn read_input(name: &str) -> String {
-    let path = format!("inputs/{name}");
-    fs::read_to_string(path).unwrap()
-}
+pub use input::read_input;
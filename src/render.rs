@@ -0,0 +1,116 @@
+use std::{collections::HashMap, fmt};
+
+use crate::containers::{Vec2D, Vec2DIndex};
+
+/// A foreground color for terminals understanding ANSI escape codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+/// Renders a [`Vec2D`] like its [`Display`](fmt::Display) implementation,
+/// but with some positions replaced by a marker or colored.
+///
+/// Created with [`Vec2D::overlay`].
+pub struct Overlay<'a, T> {
+    grid: &'a Vec2D<T>,
+    markers: HashMap<Vec2DIndex, char>,
+    colors: HashMap<Vec2DIndex, Color>,
+}
+
+impl Color {
+    /// Wraps `text` in the escape codes to show it in this color.
+    pub fn paint(self, text: impl fmt::Display) -> String {
+        format!("\x1b[{}m{text}\x1b[0m", self.code())
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+impl<'a, T> Overlay<'a, T> {
+    pub fn new(grid: &'a Vec2D<T>) -> Self {
+        Self {
+            grid,
+            markers: HashMap::new(),
+            colors: HashMap::new(),
+        }
+    }
+
+    /// Shows `marker` instead of the elements at `positions`.
+    pub fn mark<I: Into<Vec2DIndex>>(
+        mut self,
+        positions: impl IntoIterator<Item = I>,
+        marker: char,
+    ) -> Self {
+        self.markers
+            .extend(positions.into_iter().map(|p| (p.into(), marker)));
+        self
+    }
+
+    /// Shows the elements (or markers) at `positions` in `color`.
+    pub fn color<I: Into<Vec2DIndex>>(
+        mut self,
+        positions: impl IntoIterator<Item = I>,
+        color: Color,
+    ) -> Self {
+        self.colors
+            .extend(positions.into_iter().map(|p| (p.into(), color)));
+        self
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Overlay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.grid.rows() {
+            for (i, e) in row {
+                let text = match self.markers.get(&i) {
+                    Some(marker) => marker.to_string(),
+                    None => e.to_string(),
+                };
+                match self.colors.get(&i) {
+                    Some(color) => write!(f, "{}", color.paint(text))?,
+                    None => write!(f, "{text}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        containers::{Vec2D, Vec2DIndex},
+        render::Color,
+    };
+
+    #[test]
+    fn test_overlay() {
+        let vec: Vec2D<char> = "abc\ndef\n".parse().unwrap();
+        assert_eq!(vec.overlay().to_string(), "abc\ndef\n");
+
+        let overlay = vec
+            .overlay()
+            .mark([Vec2DIndex::new(0, 0), Vec2DIndex::new(1, 2)], '#')
+            .color([Vec2DIndex::new(1, 2), Vec2DIndex::new(0, 1)], Color::Red);
+        assert_eq!(
+            overlay.to_string(),
+            "#\x1b[31mb\x1b[0mc\nde\x1b[31m#\x1b[0m\n"
+        );
+    }
+}
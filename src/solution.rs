@@ -0,0 +1,121 @@
+use std::fmt::Display;
+
+use crate::{
+    answers::Part,
+    error::Result,
+    timing::{timed, Timings},
+};
+
+/// A solver for one day of the puzzle calendar.
+///
+/// The input is parsed once and then handed to both parts.
+pub trait Solution {
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Example input from the puzzle description.
+    const EXAMPLE: &'static str;
+
+    /// Example input for part 2, for puzzles whose description uses a
+    /// different one than for part 1.
+    const EXAMPLE_PART2: &'static str = Self::EXAMPLE;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// The answers of both parts of a day, rendered for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+impl Answers {
+    pub fn part(&self, part: Part) -> &str {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+}
+
+/// Object-safe view of a [`Solution`], so that days with different input and
+/// answer types can be run through the same interface.
+pub trait Runner {
+    fn run_timed(&self, input: &str) -> Result<(Answers, Timings)>;
+
+    /// Runs both parts on their example inputs.
+    fn run_example_timed(&self) -> Result<(Answers, Timings)>;
+
+    fn run(&self, input: &str) -> Result<Answers> {
+        Ok(self.run_timed(input)?.0)
+    }
+
+    fn run_example(&self) -> Result<Answers> {
+        Ok(self.run_example_timed()?.0)
+    }
+}
+
+impl<S: Solution> Runner for S {
+    fn run_timed(&self, input: &str) -> Result<(Answers, Timings)> {
+        let (input, parse) = timed(|| S::parse(input));
+        let input = input?;
+        let (part1, part1_time) = timed(|| S::part1(&input).to_string());
+        let (part2, part2_time) = timed(|| S::part2(&input).to_string());
+
+        Ok((
+            Answers { part1, part2 },
+            Timings {
+                parse,
+                parse_part2: None,
+                part1: part1_time,
+                part2: part2_time,
+            },
+        ))
+    }
+
+    fn run_example_timed(&self) -> Result<(Answers, Timings)> {
+        if S::EXAMPLE_PART2 == S::EXAMPLE {
+            return self.run_timed(S::EXAMPLE);
+        }
+
+        let (input1, parse) = timed(|| S::parse(S::EXAMPLE));
+        let (input2, parse_part2) = timed(|| S::parse(S::EXAMPLE_PART2));
+        let (input1, input2) = (input1?, input2?);
+        let (part1, part1_time) = timed(|| S::part1(&input1).to_string());
+        let (part2, part2_time) = timed(|| S::part2(&input2).to_string());
+
+        Ok((
+            Answers { part1, part2 },
+            Timings {
+                parse,
+                parse_part2: Some(parse_part2),
+                part1: part1_time,
+                part2: part2_time,
+            },
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::days;
+
+    #[test]
+    fn test_run_example_timed() {
+        // day 1 uses the same example for both parts, day 3 does not
+        let (_, timings) = days::get(1).unwrap().run_example_timed().unwrap();
+        assert_eq!(timings.parse_part2, None);
+        let (answers, timings) = days::get(3).unwrap().run_example_timed().unwrap();
+        assert_eq!(
+            (answers.part1.as_str(), answers.part2.as_str()),
+            ("161", "48")
+        );
+        assert!(timings.parse_part2.is_some());
+    }
+}
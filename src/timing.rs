@@ -0,0 +1,82 @@
+use std::time::{Duration, Instant};
+
+/// How long the stages of one run of a solution took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    /// Parsing the separate part 2 example, for days that have one.
+    pub parse_part2: Option<Duration>,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Summary of repeated measurements of the same stage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    /// Population standard deviation.
+    pub stddev: Duration,
+}
+
+/// Runs `f` and returns its result along with how long it took.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+impl Stats {
+    /// Summarizes `samples`, returns `None` if there are none.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let runs = sorted.len();
+        let min = *sorted.first()?;
+        let median = if runs % 2 == 0 {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+        let mean = sorted.iter().sum::<Duration>() / runs as u32;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / runs as f64;
+
+        Some(Self {
+            runs,
+            min,
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::timing::Stats;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        assert_eq!(Stats::from_samples(&[]), None);
+
+        let stats = Stats::from_samples(&[ms(4), ms(2), ms(8), ms(6)]).unwrap();
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, ms(2));
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(stats.mean, ms(5));
+        assert_eq!(stats.stddev.as_micros(), 2236);
+
+        let stats = Stats::from_samples(&[ms(3)]).unwrap();
+        assert_eq!((stats.median, stats.stddev), (ms(3), Duration::ZERO));
+    }
+}
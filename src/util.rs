@@ -25,9 +25,19 @@ pub fn split_digits<const R: u64>(n: u64, from_right: usize) -> (u64, u64) {
     (n / div, n % div)
 }
 
+/// Greatest common divisor, `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::util::{concat_numbers, count_digits, split_digits};
+    use proptest::prelude::*;
+
+    use crate::util::{concat_numbers, count_digits, gcd, split_digits};
 
     #[test]
     fn test_concat() {
@@ -50,4 +60,32 @@ mod tests {
         assert_eq!(split_digits::<10>(1234, 2), (12, 34));
         assert_eq!(split_digits::<10>(1234, 3), (1, 234));
     }
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(gcd(0, 0), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_split_inverts_concat(a in 0..1_000_000_000u64, b in 0..1_000_000_000u64) {
+            let n = concat_numbers::<10>(a, b);
+            prop_assert_eq!(split_digits::<10>(n, count_digits::<10>(b)), (a, b));
+        }
+
+        #[test]
+        fn prop_split_inverts_concat_binary(a in 0..u32::MAX as u64, b in 0..u32::MAX as u64) {
+            let n = concat_numbers::<2>(a, b);
+            prop_assert_eq!(split_digits::<2>(n, count_digits::<2>(b)), (a, b));
+        }
+
+        #[test]
+        fn prop_count_matches_string_length(n in 1..u64::MAX) {
+            prop_assert_eq!(count_digits::<10>(n), n.to_string().len());
+            prop_assert_eq!(count_digits::<2>(n), format!("{n:b}").len());
+        }
+    }
 }
@@ -0,0 +1,80 @@
+use aoc_2024::{
+    answers::{Ledger, Part},
+    days,
+    error::Error,
+    read_input,
+};
+
+/// Checks `day` against the answers from its puzzle description.
+fn check_example(day: u32, part1: &str, part2: &str) {
+    let answers = days::get(day).unwrap().run_example().unwrap();
+    assert_eq!(answers.part1, part1, "day {day} part 1 on the example");
+    assert_eq!(answers.part2, part2, "day {day} part 2 on the example");
+}
+
+/// Checks `day` against the recorded answers of the real input, skipping
+/// it if either is missing.
+fn check_input(day: u32) {
+    let input = match read_input(&format!("day{day}")) {
+        Ok(input) => input,
+        Err(e @ Error::MissingInput(_)) => {
+            eprintln!("skipping real input of day {day}: {e}");
+            return;
+        }
+        Err(e) => panic!("day {day}: {e}"),
+    };
+    let ledger = Ledger::from_env().unwrap();
+    if Part::all().all(|part| ledger.get(day, part).is_none()) {
+        eprintln!(
+            "skipping real input of day {day}: no answers recorded in {}",
+            ledger.path().display()
+        );
+        return;
+    }
+
+    let answers = days::get(day).unwrap().run(&input).unwrap();
+    for part in Part::all() {
+        match ledger.get(day, part) {
+            Some(expected) => {
+                assert_eq!(answers.part(part), expected, "day {day} part {part}")
+            }
+            None => eprintln!("skipping day {day} part {part}: no answer recorded"),
+        }
+    }
+}
+
+macro_rules! day_tests {
+    ($($day:literal: $example:ident, $input:ident => $part1:literal, $part2:literal;)*) => {
+        $(
+            #[test]
+            fn $example() {
+                check_example($day, $part1, $part2);
+            }
+
+            #[test]
+            fn $input() {
+                check_input($day);
+            }
+        )*
+
+        #[test]
+        fn all_days_covered() {
+            assert_eq!(days::all().count(), [$($day),*].len());
+        }
+    };
+}
+
+day_tests! {
+    1: day1_example, day1_input => "11", "31";
+    2: day2_example, day2_input => "2", "4";
+    3: day3_example, day3_input => "161", "48";
+    4: day4_example, day4_input => "18", "9";
+    5: day5_example, day5_input => "143", "123";
+    6: day6_example, day6_input => "41", "6";
+    7: day7_example, day7_input => "3749", "11387";
+    8: day8_example, day8_input => "14", "34";
+    9: day9_example, day9_input => "1928", "2858";
+    10: day10_example, day10_input => "36", "81";
+    11: day11_example, day11_input => "55312", "65601038650482";
+    12: day12_example, day12_input => "1930", "1206";
+}